@@ -0,0 +1,122 @@
+//! Generators of valid moduli and in-range elements for property testing
+//!
+//! The `proptest` feature provides strategies in [`proptest`](self::proptest),
+//! the `quickcheck` feature provides `Arbitrary` newtypes in [`quickcheck`](self::quickcheck).
+//! Both mix uniformly random odd moduli with the edge cases in this module,
+//! and can restrict the moduli to primes for operations like inversion.
+#[cfg(feature = "u128")]
+use crate::prime::is_prime_u128;
+use crate::prime::is_prime_u64;
+
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+
+/// Odd moduli probing the edges of the u64 arithmetic: tiny, around 2**32 and close to 2**64
+pub const EDGE_MODULI_U64: [u64; 10] = [
+    3,
+    5,
+    0xffff_ffff,
+    0x1_0000_0001,
+    (1 << 63) - 1,
+    (1 << 63) + 1,
+    u64::MAX - 58,
+    u64::MAX - 4,
+    u64::MAX - 2,
+    u64::MAX,
+];
+
+/// Primes probing the edges of the u64 arithmetic
+pub const EDGE_PRIMES_U64: [u64; 9] = [
+    3,
+    5,
+    65537,
+    (1 << 31) - 1,
+    1_000_000_007,
+    (1 << 61) - 1,
+    0xffff_ffff_0000_0001,
+    (1 << 63) + 29,
+    u64::MAX - 58,
+];
+
+/// Odd moduli probing the edges of the u128 arithmetic: tiny, around 2**64 and close to 2**128
+#[cfg(feature = "u128")]
+pub const EDGE_MODULI_U128: [u128; 10] = [
+    3,
+    5,
+    u64::MAX as u128,
+    (1 << 64) + 1,
+    (1 << 127) - 1,
+    (1 << 127) + 1,
+    u128::MAX - 158,
+    u128::MAX - 4,
+    u128::MAX - 2,
+    u128::MAX,
+];
+
+/// Primes probing the edges of the u128 arithmetic
+#[cfg(feature = "u128")]
+pub const EDGE_PRIMES_U128: [u128; 7] = [
+    3,
+    65537,
+    u64::MAX as u128 - 58,
+    (1 << 64) + 13,
+    (1 << 89) - 1,
+    (1 << 127) - 1,
+    u128::MAX - 158,
+];
+
+/// Largest prime not above `start`, wrapping to the largest u64 prime below 3
+fn prime_at_most_u64(start: u64) -> u64 {
+    let mut candidate = if start < 3 { u64::MAX } else { (start - 1) | 1 };
+    while !is_prime_u64(candidate) {
+        candidate -= 2;
+        if candidate < 3 {
+            candidate = u64::MAX;
+        }
+    }
+    candidate
+}
+
+/// Largest probable prime not above `start`, wrapping to the largest u128 prime below 3
+#[cfg(feature = "u128")]
+fn prime_at_most_u128(start: u128) -> u128 {
+    let mut candidate = if start < 3 {
+        u128::MAX
+    } else {
+        (start - 1) | 1
+    };
+    while !is_prime_u128(candidate) {
+        candidate -= 2;
+        if candidate < 3 {
+            candidate = u128::MAX;
+        }
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edge_primes() {
+        assert!(EDGE_PRIMES_U64.iter().all(|&p| is_prime_u64(p)));
+        assert!(EDGE_MODULI_U64.iter().all(|&m| m % 2 == 1));
+        assert!(!is_prime_u64(u64::MAX));
+        // Strong pseudoprime to the bases 2, 3, 5, 7, 11, 13, 17, 19, 23
+        assert!(!is_prime_u64(3_825_123_056_546_413_051));
+        assert_eq!(prime_at_most_u64(u64::MAX), u64::MAX - 58);
+        assert_eq!(prime_at_most_u64(1), u64::MAX - 58);
+        assert_eq!(prime_at_most_u64(100), 97);
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn test_edge_primes_u128() {
+        assert!(EDGE_PRIMES_U128.iter().all(|&p| is_prime_u128(p)));
+        assert!(EDGE_MODULI_U128.iter().all(|&m| m % 2 == 1));
+        assert_eq!(prime_at_most_u128(u128::MAX), u128::MAX - 158);
+    }
+}
@@ -0,0 +1,73 @@
+//! proptest strategies for moduli and elements
+//!
+//! Elements borrow their field, so the strategies generate plain values,
+//! to be converted once the field is set up:
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use redc::arbitrary::proptest::{element_u64, odd_modulus_u64};
+//! use redc::element::Element;
+//! use redc::Redc;
+//!
+//! proptest!(|((modulus, value) in odd_modulus_u64().prop_flat_map(|m| (Just(m), element_u64(m))))| {
+//!     let field = modulus.setup_field();
+//!     prop_assert_eq!(field.wrap_element(value).to_normal(), value);
+//! });
+//! ```
+use ::proptest::prelude::*;
+use ::proptest::sample::select;
+
+#[cfg(feature = "u128")]
+use super::{prime_at_most_u128, EDGE_MODULI_U128, EDGE_PRIMES_U128};
+use super::{prime_at_most_u64, EDGE_MODULI_U64, EDGE_PRIMES_U64};
+
+/// Odd moduli greater than one, a quarter of them taken from the edge cases
+pub fn odd_modulus_u64() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        1 => select(EDGE_MODULI_U64.to_vec()),
+        3 => (2u64..).prop_map(|x| x | 1),
+    ]
+}
+
+/// Prime moduli, a quarter of them taken from the edge cases
+pub fn prime_modulus_u64() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        1 => select(EDGE_PRIMES_U64.to_vec()),
+        3 => any::<u64>().prop_map(prime_at_most_u64),
+    ]
+}
+
+/// Values in [0, modulus), favouring 0, 1 and modulus - 1
+pub fn element_u64(modulus: u64) -> impl Strategy<Value = u64> {
+    prop_oneof![
+        1 => select(alloc::vec![0, 1, modulus - 1]),
+        3 => 0..modulus,
+    ]
+}
+
+/// Odd moduli greater than one, a quarter of them taken from the edge cases
+#[cfg(feature = "u128")]
+pub fn odd_modulus_u128() -> impl Strategy<Value = u128> {
+    prop_oneof![
+        1 => select(EDGE_MODULI_U128.to_vec()),
+        3 => (2u128..).prop_map(|x| x | 1),
+    ]
+}
+
+/// Probable prime moduli, a quarter of them taken from the edge cases
+#[cfg(feature = "u128")]
+pub fn prime_modulus_u128() -> impl Strategy<Value = u128> {
+    prop_oneof![
+        1 => select(EDGE_PRIMES_U128.to_vec()),
+        3 => any::<u128>().prop_map(prime_at_most_u128),
+    ]
+}
+
+/// Values in [0, modulus), favouring 0, 1 and modulus - 1
+#[cfg(feature = "u128")]
+pub fn element_u128(modulus: u128) -> impl Strategy<Value = u128> {
+    prop_oneof![
+        1 => select(alloc::vec![0, 1, modulus - 1]),
+        3 => 0..modulus,
+    ]
+}
@@ -0,0 +1,104 @@
+//! quickcheck `Arbitrary` newtypes for moduli and elements
+//!
+//! Elements borrow their field, so [`FieldElements`] generates a modulus together with plain values,
+//! to be converted once the field is set up.
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use ::quickcheck::{Arbitrary, Gen};
+
+#[cfg(feature = "u128")]
+use super::{prime_at_most_u128, EDGE_MODULI_U128, EDGE_PRIMES_U128};
+use super::{prime_at_most_u64, EDGE_MODULI_U64, EDGE_PRIMES_U64};
+
+/// Odd modulus greater than one, a quarter of them taken from the edge cases
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OddModulus<T>(pub T);
+
+/// Prime modulus, a quarter of them taken from the edge cases
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrimeModulus<T>(pub T);
+
+/// A modulus with values in [0, modulus), favouring 0, 1 and modulus - 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldElements<M, T> {
+    pub modulus: M,
+    pub elements: Vec<T>,
+}
+
+fn edge_case(g: &mut Gen) -> bool {
+    u8::arbitrary(g) % 4 == 0
+}
+
+macro_rules! impl_arbitrary {
+    ($t:ty, $edge_moduli:expr, $edge_primes:expr, $prime_at_most:expr) => {
+        impl Arbitrary for OddModulus<$t> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                if edge_case(g) {
+                    Self(*g.choose(&$edge_moduli).unwrap())
+                } else {
+                    Self(<$t>::arbitrary(g).max(2) | 1)
+                }
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.0.shrink().filter(|&m| m > 1 && m % 2 == 1).map(Self))
+            }
+        }
+
+        impl Arbitrary for PrimeModulus<$t> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                if edge_case(g) {
+                    Self(*g.choose(&$edge_primes).unwrap())
+                } else {
+                    Self($prime_at_most(<$t>::arbitrary(g)))
+                }
+            }
+        }
+
+        impl<M: Arbitrary + Into<$t>> Arbitrary for FieldElements<M, $t> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                let modulus = M::arbitrary(g);
+                let m: $t = modulus.clone().into();
+                let elements = (0..g.size().max(1))
+                    .map(|_| match u8::arbitrary(g) % 8 {
+                        0 => 0,
+                        1 => 1,
+                        2 => m - 1,
+                        _ => <$t>::arbitrary(g) % m,
+                    })
+                    .collect();
+                Self { modulus, elements }
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                let modulus = self.modulus.clone();
+                Box::new(
+                    self.elements
+                        .shrink()
+                        .filter(|elements| !elements.is_empty())
+                        .map(move |elements| Self {
+                            modulus: modulus.clone(),
+                            elements,
+                        }),
+                )
+            }
+        }
+
+        impl From<OddModulus<$t>> for $t {
+            fn from(modulus: OddModulus<$t>) -> Self {
+                modulus.0
+            }
+        }
+
+        impl From<PrimeModulus<$t>> for $t {
+            fn from(modulus: PrimeModulus<$t>) -> Self {
+                modulus.0
+            }
+        }
+    };
+}
+
+impl_arbitrary!(u64, EDGE_MODULI_U64, EDGE_PRIMES_U64, prime_at_most_u64);
+#[cfg(feature = "u128")]
+impl_arbitrary!(u128, EDGE_MODULI_U128, EDGE_PRIMES_U128, prime_at_most_u128);
@@ -0,0 +1,37 @@
+//! Batch operations which need scratch space on the heap
+use alloc::vec::Vec;
+use core::ops::Mul;
+
+use crate::element::Element;
+
+/// Replace every element by its multiplicative inverse using Montgomery's trick,
+/// trading all but one inversion for three multiplications each.
+///
+/// All elements need to be invertible, a single zero element zeroes the whole slice.
+pub fn batch_invert<E>(elements: &mut [E])
+where
+    E: Element + Mul<Output = E>,
+{
+    let mut prefix = Vec::with_capacity(elements.len());
+    let mut iter = elements.iter().cloned();
+    let mut acc = match iter.next() {
+        Some(first) => first,
+        None => return,
+    };
+    for x in iter {
+        prefix.push(acc.clone());
+        acc = acc * x;
+    }
+    let mut inverse = acc.invert();
+    for (x, before) in elements
+        .iter_mut()
+        .skip(1)
+        .rev()
+        .zip(prefix.into_iter().rev())
+    {
+        let next_inverse = inverse.clone() * x.clone();
+        *x = inverse * before;
+        inverse = next_inverse;
+    }
+    elements[0] = inverse;
+}
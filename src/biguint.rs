@@ -0,0 +1,236 @@
+//! Pure Rust arbitrary precision backend, for targets where GMP is not available
+use alloc::vec::Vec;
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+use crate::element::Element;
+use crate::{Field, Redc};
+
+/// Montgomery field over an arbitrary precision odd modulus, built on `num-bigint`
+///
+/// The field is never mutated after `setup_field`, it is `Send` and `Sync`
+/// and can be shared by reference between threads.
+#[derive(Debug, Clone)]
+pub struct BigUintField {
+    prime: BigUint,
+    prime_inverted: BigUint,
+    r_squared: BigUint,
+    r_count: u64,
+    /// R - 1, to keep the lower `r_count` bits without reallocating a mask on every reduction
+    r_mask: BigUint,
+}
+
+#[derive(Clone, Debug)]
+pub struct BigUintElement<'a> {
+    element: BigUint,
+    field: &'a BigUintField,
+}
+
+impl<'a> BigUintElement<'a> {
+    pub fn new(element: BigUint, field: &'a BigUintField) -> Self {
+        Self { element, field }
+    }
+}
+
+fn biguint_calc_prime_inverse(prime: &BigUint, r_count: u64) -> BigUint {
+    assert!(
+        prime.bit(0),
+        "Prime {} needs to be coprime to base 2**x, but is not (cannot be divisible by 2)",
+        prime
+    );
+    let mut prime_inv_mod = BigUint::one();
+    let mut mod_mask = BigUint::from(0b11u32);
+    for bit in 1..r_count {
+        let prime_mod = prime & &mod_mask;
+        if (prime_mod * &prime_inv_mod) & &mod_mask != mod_mask {
+            prime_inv_mod.set_bit(bit, true);
+        }
+        mod_mask.set_bit(bit + 1, true);
+    }
+    prime_inv_mod
+}
+
+impl BigUintField {
+    // Convert to montgomery representation, and use a wrapper to do arithmetic without needing to do manual redc operations
+    pub fn wrap_element(&self, element: BigUint) -> BigUintElement<'_> {
+        BigUintElement::new(element.to_montgomery(self), self)
+    }
+
+    /// Number of bytes in the canonical encoding, enough to hold the prime
+    pub fn byte_len(&self) -> usize {
+        (self.r_count as usize).div_ceil(8)
+    }
+
+    fn canonical_element(&self, value: BigUint) -> Option<BigUintElement<'_>> {
+        if value < self.prime {
+            Some(BigUintElement::new(
+                value.to_montgomery_unchecked(self),
+                self,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Decode a little endian normal representative of `byte_len()` bytes, `None` if it is not below the prime
+    pub fn from_bytes_le(&self, bytes: &[u8]) -> Option<BigUintElement<'_>> {
+        if bytes.len() != self.byte_len() {
+            return None;
+        }
+        self.canonical_element(BigUint::from_bytes_le(bytes))
+    }
+
+    /// Decode a big endian normal representative of `byte_len()` bytes, `None` if it is not below the prime
+    pub fn from_bytes_be(&self, bytes: &[u8]) -> Option<BigUintElement<'_>> {
+        if bytes.len() != self.byte_len() {
+            return None;
+        }
+        self.canonical_element(BigUint::from_bytes_be(bytes))
+    }
+
+    /// Reduce a little endian value of `2 * byte_len()` bytes modulo the prime, with negligible bias
+    ///
+    /// # Panics
+    /// If `bytes` is not exactly `2 * byte_len()` long
+    pub fn from_uniform_bytes(&self, bytes: &[u8]) -> BigUintElement<'_> {
+        assert_eq!(
+            bytes.len(),
+            2 * self.byte_len(),
+            "Uniform bytes need to be twice as long as the modulus"
+        );
+        self.wrap_element(BigUint::from_bytes_le(bytes))
+    }
+}
+
+impl<'a> BigUintElement<'a> {
+    /// Little endian encoding of the normal representative, `field.byte_len()` bytes long
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        let mut bytes = self.clone().to_normal().to_bytes_le();
+        bytes.resize(self.field.byte_len(), 0);
+        bytes
+    }
+
+    /// Big endian encoding of the normal representative, `field.byte_len()` bytes long
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let mut bytes = self.to_bytes_le();
+        bytes.reverse();
+        bytes
+    }
+}
+
+impl Field<BigUint> for BigUintField {
+    fn redc(&self, value: <BigUint as Redc>::SourceType) -> BigUint {
+        let m = ((&value & &self.r_mask) * &self.prime_inverted) & &self.r_mask;
+        let tw = (m * &self.prime + value) >> self.r_count;
+        if tw >= self.prime {
+            tw - &self.prime
+        } else {
+            tw
+        }
+    }
+}
+
+impl Redc for BigUint {
+    type SourceType = Self;
+    type FieldType = BigUintField;
+
+    fn setup_field(self) -> Self::FieldType {
+        let r_count = self.bits();
+        let r = BigUint::one() << r_count;
+        let r_squared = (&r * &r) % &self;
+        Self::FieldType {
+            prime_inverted: biguint_calc_prime_inverse(&self, r_count),
+            prime: self,
+            r_squared,
+            r_count,
+            r_mask: r - 1u32,
+        }
+    }
+
+    fn to_montgomery_unchecked(self, field: &Self::FieldType) -> Self {
+        debug_assert!(self < field.prime);
+        field.redc(self * &field.r_squared)
+    }
+
+    fn to_montgomery(self, field: &Self::FieldType) -> Self {
+        field.redc((self % &field.prime) * &field.r_squared)
+    }
+
+    fn to_normal(self, field: &Self::FieldType) -> Self {
+        field.redc(self)
+    }
+
+    fn mod_pow(self, exponent: Self, field: &Self::FieldType) -> Self {
+        let mut result = if exponent.bit(0) {
+            self.clone()
+        } else {
+            Self::one().to_montgomery_unchecked(field)
+        };
+        let mut power = self;
+        for bit in 1..exponent.bits() {
+            power = field.redc(&power * &power);
+            if exponent.bit(bit) {
+                result = field.redc(result * &power);
+            }
+        }
+        result
+    }
+}
+
+impl<'a> Element for BigUintElement<'a> {
+    type UnderlyingType = BigUint;
+
+    fn invert(mut self) -> Self {
+        self.element = self
+            .element
+            .mod_pow(self.field.prime.clone() - 2u32, self.field);
+        self
+    }
+
+    fn pow(mut self, exponent: Self) -> Self {
+        self.element = self.element.mod_pow(exponent.element, self.field);
+        self
+    }
+
+    fn internal(&self) -> &Self::UnderlyingType {
+        &self.element
+    }
+
+    fn to_normal(self) -> Self::UnderlyingType {
+        self.element.to_normal(self.field)
+    }
+}
+
+impl<'a> core::ops::Add for BigUintElement<'a> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.element += rhs.element;
+        if self.element >= self.field.prime {
+            self.element -= &self.field.prime;
+        }
+        self
+    }
+}
+
+impl<'a> core::ops::Sub for BigUintElement<'a> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        if self.element < rhs.element {
+            self.element += &self.field.prime;
+        }
+        self.element -= rhs.element;
+        self
+    }
+}
+
+impl<'a> core::ops::Mul for BigUintElement<'a> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        self.element = self.field.redc(self.element * rhs.element);
+        self
+    }
+}
@@ -0,0 +1,159 @@
+//! Canonical byte encoding of elements, and wide reduction of byte strings into a field
+//!
+//! Elements are encoded as their normal representative with the byte width of the modulus type.
+//! Decoding is canonical: values not below the modulus are rejected instead of being reduced.
+//! `from_uniform_bytes` takes twice the width, interpreted as little endian, and reduces it modulo the prime,
+//! which maps uniformly random bytes to a field element with negligible bias.
+//!
+//! The signed, `num-bigint` and `crypto-bigint` backends provide the same methods in their own modules.
+use crate::constant::{ConstPrimeElement, ConstPrimeModulus};
+use crate::element::{Element, PrimIntElement};
+#[cfg(feature = "rug")]
+use crate::{element::RugElement, RugField};
+use crate::{PrimIntField, Redc};
+#[cfg(feature = "rug")]
+use alloc::vec::Vec;
+#[cfg(feature = "u128")]
+use twoword::TwoWord;
+
+macro_rules! impl_prim_int_bytes {
+    ($t:ty, $bytes:expr) => {
+        impl<'a> PrimIntElement<'a, $t> {
+            /// Little endian encoding of the normal representative
+            pub fn to_bytes_le(&self) -> [u8; $bytes] {
+                self.to_normal().to_le_bytes()
+            }
+
+            /// Big endian encoding of the normal representative
+            pub fn to_bytes_be(&self) -> [u8; $bytes] {
+                self.to_normal().to_be_bytes()
+            }
+        }
+
+        impl PrimIntField<$t> {
+            /// Decode a little endian normal representative, `None` if it is not below the prime
+            pub fn from_bytes_le(&self, bytes: &[u8; $bytes]) -> Option<PrimIntElement<'_, $t>> {
+                self.try_from_canonical(<$t>::from_le_bytes(*bytes)).ok()
+            }
+
+            /// Decode a big endian normal representative, `None` if it is not below the prime
+            pub fn from_bytes_be(&self, bytes: &[u8; $bytes]) -> Option<PrimIntElement<'_, $t>> {
+                self.try_from_canonical(<$t>::from_be_bytes(*bytes)).ok()
+            }
+        }
+    };
+}
+
+impl_prim_int_bytes!(u64, 8);
+#[cfg(feature = "u128")]
+impl_prim_int_bytes!(u128, 16);
+
+impl PrimIntField<u64> {
+    /// Reduce a little endian double width value modulo the prime
+    pub fn from_uniform_bytes(&self, bytes: &[u8; 16]) -> PrimIntElement<'_, u64> {
+        let value = u128::from_le_bytes(*bytes) % u128::from(self.prime);
+        #[allow(clippy::cast_possible_truncation)]
+        self.raw_element((value as u64).to_montgomery_unchecked(self))
+    }
+}
+
+#[cfg(feature = "u128")]
+impl PrimIntField<u128> {
+    /// Reduce a little endian double width value modulo the prime
+    pub fn from_uniform_bytes(&self, bytes: &[u8; 32]) -> PrimIntElement<'_, u128> {
+        let mut lower = [0u8; 16];
+        let mut higher = [0u8; 16];
+        lower.copy_from_slice(&bytes[..16]);
+        higher.copy_from_slice(&bytes[16..]);
+        let value = TwoWord {
+            lower: u128::from_le_bytes(lower),
+            higher: u128::from_le_bytes(higher),
+        } % TwoWord::from(self.prime);
+        self.raw_element(value.lower.to_montgomery_unchecked(self))
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> RugElement<'a> {
+    /// Little endian encoding of the normal representative, `field.byte_len()` bytes long
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        self.to_bytes(rug::integer::Order::Lsf)
+    }
+
+    /// Big endian encoding of the normal representative, `field.byte_len()` bytes long
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        self.to_bytes(rug::integer::Order::Msf)
+    }
+
+    fn to_bytes(&self, order: rug::integer::Order) -> Vec<u8> {
+        let mut bytes = alloc::vec![0u8; self.field().byte_len()];
+        self.clone().to_normal().write_digits(&mut bytes, order);
+        bytes
+    }
+}
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Number of bytes in the canonical encoding, enough to hold the prime
+    pub fn byte_len(&self) -> usize {
+        self.prime.significant_bits().div_ceil(8) as usize
+    }
+
+    fn decode_element(&self, bytes: &[u8], order: rug::integer::Order) -> Option<RugElement<'_>> {
+        if bytes.len() != self.byte_len() {
+            return None;
+        }
+        self.try_from_canonical(rug::Integer::from_digits(bytes, order))
+            .ok()
+    }
+
+    /// Decode a little endian normal representative of `byte_len()` bytes, `None` if it is not below the prime
+    pub fn from_bytes_le(&self, bytes: &[u8]) -> Option<RugElement<'_>> {
+        self.decode_element(bytes, rug::integer::Order::Lsf)
+    }
+
+    /// Decode a big endian normal representative of `byte_len()` bytes, `None` if it is not below the prime
+    pub fn from_bytes_be(&self, bytes: &[u8]) -> Option<RugElement<'_>> {
+        self.decode_element(bytes, rug::integer::Order::Msf)
+    }
+
+    /// Reduce a little endian value of `2 * byte_len()` bytes modulo the prime, with negligible bias
+    ///
+    /// # Panics
+    /// If `bytes` is not exactly `2 * byte_len()` long
+    pub fn from_uniform_bytes(&self, bytes: &[u8]) -> RugElement<'_> {
+        assert_eq!(
+            bytes.len(),
+            2 * self.byte_len(),
+            "Uniform bytes need to be twice as long as the modulus"
+        );
+        self.wrap_element(rug::Integer::from_digits(bytes, rug::integer::Order::Lsf))
+    }
+}
+
+impl<P: ConstPrimeModulus> ConstPrimeElement<P> {
+    /// Little endian encoding of the normal representative
+    pub fn to_bytes_le(self) -> [u8; 8] {
+        self.to_u64().to_le_bytes()
+    }
+
+    /// Big endian encoding of the normal representative
+    pub fn to_bytes_be(self) -> [u8; 8] {
+        self.to_u64().to_be_bytes()
+    }
+
+    /// Decode a little endian normal representative, `None` if it is not below the modulus
+    pub fn from_bytes_le(bytes: &[u8; 8]) -> Option<Self> {
+        Self::FIELD.from_bytes_le(bytes).map(Self::from_element)
+    }
+
+    /// Decode a big endian normal representative, `None` if it is not below the modulus
+    pub fn from_bytes_be(bytes: &[u8; 8]) -> Option<Self> {
+        Self::FIELD.from_bytes_be(bytes).map(Self::from_element)
+    }
+
+    /// Reduce a little endian double width value modulo the prime
+    pub fn from_uniform_bytes(bytes: &[u8; 16]) -> Self {
+        Self::from_element(Self::FIELD.from_uniform_bytes(bytes))
+    }
+}
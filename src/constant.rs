@@ -0,0 +1,401 @@
+//! Prime fields with a modulus known at compile time
+//!
+//! Elements do not need to carry a reference to their field, so they are `'static` and `Copy`
+//! and can implement field traits like `ff::PrimeField`, which construct elements without a field.
+use core::fmt::Debug;
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::element::{Element, FieldOps, PrimIntElement};
+use crate::{p_redc_u64, PrimIntField, Redc};
+
+/// Compile time description of a prime field with a modulus fitting into a u64
+///
+/// ```
+/// #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+/// struct Goldilocks;
+///
+/// impl redc::constant::ConstPrimeModulus for Goldilocks {
+///     const MODULUS: u64 = 0xffff_ffff_0000_0001;
+///     const GENERATOR: u64 = 7;
+/// }
+///
+/// type Fp = redc::constant::ConstPrimeElement<Goldilocks>;
+/// assert_eq!((Fp::new(3) * Fp::new(5)).to_u64(), 15);
+/// ```
+pub trait ConstPrimeModulus:
+    'static + Copy + Debug + Default + Eq + core::hash::Hash + Send + Sync
+{
+    /// Odd prime modulus
+    const MODULUS: u64;
+    /// Generator of the multiplicative group, which also has to be a quadratic nonresidue
+    const GENERATOR: u64;
+}
+
+/// Element in montgomery form of the prime field described by `P`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ConstPrimeElement<P> {
+    element: u64,
+    modulus: PhantomData<P>,
+}
+
+impl<P: ConstPrimeModulus> ConstPrimeElement<P> {
+    pub const FIELD: PrimIntField<u64> = PrimIntField::setup_const(P::MODULUS);
+
+    const fn raw(element: u64) -> Self {
+        Self {
+            element,
+            modulus: PhantomData,
+        }
+    }
+
+    const fn const_montgomery(value: u64) -> Self {
+        let field = Self::FIELD;
+        Self::raw(p_redc_u64(
+            &field,
+            (value % field.prime) as u128 * field.r_squared as u128,
+        ))
+    }
+
+    /// Convert to montgomery representation
+    pub fn new(value: u64) -> Self {
+        Self::raw(value.to_montgomery(&Self::FIELD))
+    }
+
+    /// Wrap a value already in montgomery representation
+    pub fn from_montgomery(element: u64) -> Self {
+        Self::raw(element)
+    }
+
+    /// Normal representation in [0, MODULUS)
+    pub fn to_u64(self) -> u64 {
+        self.element.to_normal(&Self::FIELD)
+    }
+
+    /// Borrow the element as an element of the runtime field
+    pub fn as_element(self) -> PrimIntElement<'static, u64> {
+        PrimIntElement::new(self.element, &Self::FIELD)
+    }
+
+    pub(crate) fn from_element(element: PrimIntElement<'_, u64>) -> Self {
+        Self::raw(*element.internal())
+    }
+}
+
+impl<P: ConstPrimeModulus> From<u64> for ConstPrimeElement<P> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<P: ConstPrimeModulus> Element for ConstPrimeElement<P> {
+    type UnderlyingType = u64;
+
+    fn invert(self) -> Self {
+        Self::from_element(self.as_element().invert())
+    }
+
+    fn pow(self, exponent: Self) -> Self {
+        Self::raw(self.element.mod_pow(exponent.element, &Self::FIELD))
+    }
+
+    fn internal(&self) -> &Self::UnderlyingType {
+        &self.element
+    }
+
+    fn to_normal(self) -> Self::UnderlyingType {
+        self.to_u64()
+    }
+}
+
+impl<P: ConstPrimeModulus> FieldOps for ConstPrimeElement<P> {
+    type Field = PrimIntField<u64>;
+
+    fn field(&self) -> &Self::Field {
+        &Self::FIELD
+    }
+
+    fn zero(&self) -> Self {
+        Self::raw(0)
+    }
+
+    fn one(&self) -> Self {
+        Self::const_montgomery(1)
+    }
+
+    fn element_from_u64(&self, value: u64) -> Self {
+        Self::new(value)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.element == 0
+    }
+
+    fn pow_u64(&self, exponent: u64) -> Self {
+        num_traits::Pow::pow(*self, exponent)
+    }
+}
+
+impl<P: ConstPrimeModulus> Add for ConstPrimeElement<P> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_element(self.as_element() + rhs.as_element())
+    }
+}
+
+impl<P: ConstPrimeModulus> Sub for ConstPrimeElement<P> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::from_element(self.as_element() - rhs.as_element())
+    }
+}
+
+impl<P: ConstPrimeModulus> Mul for ConstPrimeElement<P> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_element(self.as_element() * rhs.as_element())
+    }
+}
+
+impl<P: ConstPrimeModulus> Neg for ConstPrimeElement<P> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::default() - self
+    }
+}
+
+macro_rules! impl_ref_ops {
+    ($($op:ident, $method:ident, $assign_op:ident, $assign_method:ident);*) => {$(
+        impl<'a, P: ConstPrimeModulus> $op<&'a Self> for ConstPrimeElement<P> {
+            type Output = Self;
+
+            fn $method(self, rhs: &'a Self) -> Self::Output {
+                self.$method(*rhs)
+            }
+        }
+
+        impl<P: ConstPrimeModulus> $assign_op for ConstPrimeElement<P> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+
+        impl<'a, P: ConstPrimeModulus> $assign_op<&'a Self> for ConstPrimeElement<P> {
+            fn $assign_method(&mut self, rhs: &'a Self) {
+                *self = self.$method(*rhs);
+            }
+        }
+    )*};
+}
+
+impl_ref_ops!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign
+);
+
+impl<P: ConstPrimeModulus> Sum for ConstPrimeElement<P> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<'a, P: ConstPrimeModulus> Sum<&'a Self> for ConstPrimeElement<P> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), Add::add)
+    }
+}
+
+impl<P: ConstPrimeModulus> Product for ConstPrimeElement<P> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::const_montgomery(1), Mul::mul)
+    }
+}
+
+impl<'a, P: ConstPrimeModulus> Product<&'a Self> for ConstPrimeElement<P> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::const_montgomery(1), Mul::mul)
+    }
+}
+
+impl<P: ConstPrimeModulus> num_traits::Zero for ConstPrimeElement<P> {
+    fn zero() -> Self {
+        Self::raw(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.element == 0
+    }
+}
+
+impl<P: ConstPrimeModulus> num_traits::One for ConstPrimeElement<P> {
+    fn one() -> Self {
+        Self::const_montgomery(1)
+    }
+
+    fn is_one(&self) -> bool {
+        *self == Self::const_montgomery(1)
+    }
+}
+
+impl<P: ConstPrimeModulus> num_traits::Inv for ConstPrimeElement<P> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        self.invert()
+    }
+}
+
+impl<P: ConstPrimeModulus> num_traits::Pow<u64> for ConstPrimeElement<P> {
+    type Output = Self;
+
+    fn pow(self, exponent: u64) -> Self::Output {
+        Self::from_element(num_traits::Pow::pow(self.as_element(), exponent))
+    }
+}
+
+impl<P: ConstPrimeModulus> core::ops::Div for ConstPrimeElement<P> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::from_element(self.as_element() / rhs.as_element())
+    }
+}
+
+impl<P: ConstPrimeModulus> num_traits::CheckedDiv for ConstPrimeElement<P> {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        self.as_element()
+            .checked_div(&v.as_element())
+            .map(Self::from_element)
+    }
+}
+
+#[cfg(feature = "ff")]
+mod prime_field {
+    use rand_core::RngCore;
+    use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+    use super::{ConstPrimeElement, ConstPrimeModulus};
+    use crate::{p_redc_u64, Redc};
+
+    /// "0x" followed by the modulus as 16 big endian hex digits
+    const fn modulus_hex(modulus: u64) -> [u8; 18] {
+        let digits = b"0123456789abcdef";
+        let mut hex = [b'0'; 18];
+        hex[1] = b'x';
+        let mut i = 0;
+        while i < 16 {
+            hex[17 - i] = digits[((modulus >> (4 * i)) & 0xf) as usize];
+            i += 1;
+        }
+        hex
+    }
+
+    impl<P: ConstPrimeModulus> ConstPrimeElement<P> {
+        const MODULUS_HEX: [u8; 18] = modulus_hex(P::MODULUS);
+
+        const fn const_pow(self, mut exponent: u64) -> Self {
+            let field = Self::FIELD;
+            let mut result = Self::const_montgomery(1).element;
+            let mut power = self.element;
+            while exponent != 0 {
+                if exponent % 2 == 1 {
+                    result = p_redc_u64(&field, result as u128 * power as u128);
+                }
+                power = p_redc_u64(&field, power as u128 * power as u128);
+                exponent >>= 1;
+            }
+            Self::raw(result)
+        }
+    }
+
+    impl<P: ConstPrimeModulus> ConditionallySelectable for ConstPrimeElement<P> {
+        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+            Self::raw(u64::conditional_select(&a.element, &b.element, choice))
+        }
+    }
+
+    impl<P: ConstPrimeModulus> ConstantTimeEq for ConstPrimeElement<P> {
+        fn ct_eq(&self, other: &Self) -> Choice {
+            self.element.ct_eq(&other.element)
+        }
+    }
+
+    impl<P: ConstPrimeModulus> ff::Field for ConstPrimeElement<P> {
+        const ZERO: Self = Self::raw(0);
+        const ONE: Self = Self::const_montgomery(1);
+
+        /// Rejection sampling on the bit length of the modulus
+        fn random(mut rng: impl RngCore) -> Self {
+            let shift = P::MODULUS.leading_zeros();
+            loop {
+                let candidate = rng.next_u64() >> shift;
+                if candidate < P::MODULUS {
+                    return Self::raw(candidate.to_montgomery_unchecked(&Self::FIELD));
+                }
+            }
+        }
+
+        fn square(&self) -> Self {
+            *self * *self
+        }
+
+        fn double(&self) -> Self {
+            *self + *self
+        }
+
+        fn invert(&self) -> CtOption<Self> {
+            CtOption::new(
+                self.const_pow(P::MODULUS - 2),
+                !self.ct_eq(&<Self as ff::Field>::ZERO),
+            )
+        }
+
+        fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+            ff::helpers::sqrt_ratio_generic(num, div)
+        }
+
+        fn sqrt(&self) -> CtOption<Self> {
+            let t = (P::MODULUS - 1) >> <Self as ff::PrimeField>::S;
+            ff::helpers::sqrt_tonelli_shanks(self, [(t - 1) / 2])
+        }
+    }
+
+    impl<P: ConstPrimeModulus> ff::PrimeField for ConstPrimeElement<P> {
+        /// Normal representation in little endian byte order
+        type Repr = [u8; 8];
+
+        fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+            let value = u64::from_le_bytes(repr);
+            CtOption::new(Self::new(value), Choice::from(u8::from(value < P::MODULUS)))
+        }
+
+        fn to_repr(&self) -> Self::Repr {
+            self.to_u64().to_le_bytes()
+        }
+
+        fn is_odd(&self) -> Choice {
+            #[allow(clippy::cast_possible_truncation)]
+            Choice::from((self.to_u64() & 1) as u8)
+        }
+
+        const MODULUS: &'static str = match core::str::from_utf8(&Self::MODULUS_HEX) {
+            Ok(hex) => hex,
+            Err(_) => panic!("Hex digits are valid utf8"),
+        };
+        const NUM_BITS: u32 = u64::BITS - P::MODULUS.leading_zeros();
+        const CAPACITY: u32 = Self::NUM_BITS - 1;
+        const TWO_INV: Self = Self::const_montgomery(P::MODULUS / 2 + 1);
+        const MULTIPLICATIVE_GENERATOR: Self = Self::const_montgomery(P::GENERATOR);
+        const S: u32 = (P::MODULUS - 1).trailing_zeros();
+        const ROOT_OF_UNITY: Self =
+            Self::const_montgomery(P::GENERATOR).const_pow((P::MODULUS - 1) >> Self::S);
+        const ROOT_OF_UNITY_INV: Self = Self::ROOT_OF_UNITY.const_pow(P::MODULUS - 2);
+        const DELTA: Self = Self::const_montgomery(P::GENERATOR).const_pow(1 << Self::S);
+    }
+}
@@ -1,7 +1,9 @@
-use crate::{Field, PrimIntField, Redc, RugField};
+#[cfg(feature = "rug")]
+use crate::RugField;
+use crate::{Field, PrimIntField, Redc};
 
 pub trait Element:
-    Sized + Clone + std::fmt::Debug + std::ops::Add + std::ops::Sub + std::ops::Mul
+    Sized + Clone + core::fmt::Debug + core::ops::Add + core::ops::Sub + core::ops::Mul
 {
     type UnderlyingType: Redc;
     fn invert(self) -> Self;
@@ -10,7 +12,37 @@ pub trait Element:
     fn to_normal(self) -> Self::UnderlyingType;
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Field access and the constants generic algorithms need, on top of `Element`
+///
+/// Elements carry their field, so new elements are created from an existing one:
+/// `x.zero()`, `x.one()` and `x.element_from_u64(3)` belong to the same field as `x`.
+pub trait FieldOps:
+    Element
+    + PartialEq
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Neg<Output = Self>
+{
+    type Field;
+    fn field(&self) -> &Self::Field;
+    fn zero(&self) -> Self;
+    fn one(&self) -> Self;
+    fn element_from_u64(&self, value: u64) -> Self;
+    fn is_zero(&self) -> bool;
+    fn is_one(&self) -> bool {
+        *self == self.one()
+    }
+    fn square(&self) -> Self {
+        self.clone() * self.clone()
+    }
+    fn double(&self) -> Self {
+        self.clone() + self.clone()
+    }
+    fn pow_u64(&self, exponent: u64) -> Self;
+}
+
+#[derive(Clone, Copy)]
 pub struct PrimIntElement<'a, T> {
     element: T,
     field: &'a PrimIntField<T>,
@@ -20,6 +52,10 @@ impl<'a, T> PrimIntElement<'a, T> {
     pub fn new(element: T, field: &'a PrimIntField<T>) -> Self {
         Self { element, field }
     }
+
+    pub fn field(&self) -> &'a PrimIntField<T> {
+        self.field
+    }
 }
 
 impl<'a> Element for PrimIntElement<'a, u64> {
@@ -43,7 +79,7 @@ impl<'a> Element for PrimIntElement<'a, u64> {
     }
 }
 
-impl<'a> std::ops::Add for PrimIntElement<'a, u64> {
+impl<'a> core::ops::Add for PrimIntElement<'a, u64> {
     type Output = Self;
 
     fn add(mut self, rhs: Self) -> Self::Output {
@@ -56,7 +92,7 @@ impl<'a> std::ops::Add for PrimIntElement<'a, u64> {
     }
 }
 
-impl<'a> std::ops::Sub for PrimIntElement<'a, u64> {
+impl<'a> core::ops::Sub for PrimIntElement<'a, u64> {
     type Output = Self;
 
     fn sub(mut self, rhs: Self) -> Self::Output {
@@ -68,7 +104,7 @@ impl<'a> std::ops::Sub for PrimIntElement<'a, u64> {
     }
 }
 
-impl<'a> std::ops::Mul for PrimIntElement<'a, u64> {
+impl<'a> core::ops::Mul for PrimIntElement<'a, u64> {
     type Output = Self;
 
     fn mul(mut self, rhs: Self) -> Self::Output {
@@ -80,6 +116,7 @@ impl<'a> std::ops::Mul for PrimIntElement<'a, u64> {
     }
 }
 
+#[cfg(feature = "u128")]
 impl<'a> Element for PrimIntElement<'a, u128> {
     type UnderlyingType = u128;
 
@@ -102,7 +139,8 @@ impl<'a> Element for PrimIntElement<'a, u128> {
     }
 }
 
-impl<'a> std::ops::Add for PrimIntElement<'a, u128> {
+#[cfg(feature = "u128")]
+impl<'a> core::ops::Add for PrimIntElement<'a, u128> {
     type Output = Self;
 
     fn add(mut self, rhs: Self) -> Self::Output {
@@ -115,7 +153,8 @@ impl<'a> std::ops::Add for PrimIntElement<'a, u128> {
     }
 }
 
-impl<'a> std::ops::Sub for PrimIntElement<'a, u128> {
+#[cfg(feature = "u128")]
+impl<'a> core::ops::Sub for PrimIntElement<'a, u128> {
     type Output = Self;
 
     fn sub(mut self, rhs: Self) -> Self::Output {
@@ -127,7 +166,8 @@ impl<'a> std::ops::Sub for PrimIntElement<'a, u128> {
     }
 }
 
-impl<'a> std::ops::Mul for PrimIntElement<'a, u128> {
+#[cfg(feature = "u128")]
+impl<'a> core::ops::Mul for PrimIntElement<'a, u128> {
     type Output = Self;
 
     fn mul(mut self, rhs: Self) -> Self::Output {
@@ -144,17 +184,134 @@ impl<'a> std::ops::Mul for PrimIntElement<'a, u128> {
     }
 }
 
-#[derive(Clone, Debug)]
+/// `num-traits` operations, and the predicates they rely on, for `PrimIntElement`
+macro_rules! impl_prim_int_num_traits {
+    ($t:ty) => {
+        impl<'a> PrimIntElement<'a, $t> {
+            pub fn is_zero(&self) -> bool {
+                self.element == 0
+            }
+
+            pub fn is_one(&self) -> bool {
+                self.element == (1 as $t).to_montgomery_unchecked(self.field)
+            }
+        }
+
+        impl<'a> num_traits::Inv for PrimIntElement<'a, $t> {
+            type Output = Self;
+
+            fn inv(self) -> Self::Output {
+                self.invert()
+            }
+        }
+
+        impl<'a> num_traits::Pow<u64> for PrimIntElement<'a, $t> {
+            type Output = Self;
+
+            fn pow(mut self, exponent: u64) -> Self::Output {
+                self.element = self.element.mod_pow(<$t>::from(exponent), self.field);
+                self
+            }
+        }
+
+        impl<'a> core::ops::Div for PrimIntElement<'a, $t> {
+            type Output = Self;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                assert!(!rhs.is_zero(), "Division by zero");
+                self * rhs.invert()
+            }
+        }
+
+        impl<'a> num_traits::CheckedDiv for PrimIntElement<'a, $t> {
+            fn checked_div(&self, v: &Self) -> Option<Self> {
+                if v.is_zero() {
+                    None
+                } else {
+                    Some(*self / *v)
+                }
+            }
+        }
+    };
+}
+
+/// `FieldOps`, negation and equality for `PrimIntElement`
+macro_rules! impl_prim_int_field_ops {
+    ($t:ty) => {
+        impl<'a> PartialEq for PrimIntElement<'a, $t> {
+            fn eq(&self, other: &Self) -> bool {
+                self.element == other.element && self.field.prime == other.field.prime
+            }
+        }
+
+        impl<'a> Eq for PrimIntElement<'a, $t> {}
+
+        impl<'a> core::ops::Neg for PrimIntElement<'a, $t> {
+            type Output = Self;
+
+            fn neg(mut self) -> Self::Output {
+                if self.element != 0 {
+                    self.element = self.field.prime - self.element;
+                }
+                self
+            }
+        }
+
+        impl<'a> FieldOps for PrimIntElement<'a, $t> {
+            type Field = PrimIntField<$t>;
+
+            fn field(&self) -> &Self::Field {
+                self.field
+            }
+
+            fn zero(&self) -> Self {
+                self.field.zero()
+            }
+
+            fn one(&self) -> Self {
+                self.field.one()
+            }
+
+            fn element_from_u64(&self, value: u64) -> Self {
+                self.field.wrap_element(<$t>::from(value))
+            }
+
+            fn is_zero(&self) -> bool {
+                self.element == 0
+            }
+
+            fn pow_u64(&self, exponent: u64) -> Self {
+                num_traits::Pow::pow(*self, exponent)
+            }
+        }
+    };
+}
+
+impl_prim_int_num_traits!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_num_traits!(u128);
+impl_prim_int_field_ops!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_field_ops!(u128);
+
+#[cfg(feature = "rug")]
+#[derive(Clone)]
 pub struct RugElement<'a> {
     element: rug::Integer,
     field: &'a RugField,
 }
+#[cfg(feature = "rug")]
 impl<'a> RugElement<'a> {
     pub fn new(element: rug::Integer, field: &'a RugField) -> Self {
         Self { element, field }
     }
+
+    pub fn field(&self) -> &'a RugField {
+        self.field
+    }
 }
 
+#[cfg(feature = "rug")]
 impl<'a> Element for RugElement<'a> {
     type UnderlyingType = rug::Integer;
 
@@ -179,11 +336,12 @@ impl<'a> Element for RugElement<'a> {
     }
 }
 
-impl<'a> std::ops::Add for RugElement<'a> {
+#[cfg(feature = "rug")]
+impl<'a> core::ops::Add for RugElement<'a> {
     type Output = Self;
 
     fn add(mut self, rhs: Self) -> Self::Output {
-        self.element -= rhs.element;
+        self.element += rhs.element;
         if self.element >= self.field.prime {
             self.element -= &self.field.prime;
         }
@@ -191,19 +349,21 @@ impl<'a> std::ops::Add for RugElement<'a> {
     }
 }
 
-impl<'a> std::ops::Sub for RugElement<'a> {
+#[cfg(feature = "rug")]
+impl<'a> core::ops::Sub for RugElement<'a> {
     type Output = Self;
 
     fn sub(mut self, rhs: Self) -> Self::Output {
         self.element -= rhs.element;
-        if self.element > 0 {
+        if self.element < 0 {
             self.element += &self.field.prime;
         }
         self
     }
 }
 
-impl<'a> std::ops::Mul for RugElement<'a> {
+#[cfg(feature = "rug")]
+impl<'a> core::ops::Mul for RugElement<'a> {
     type Output = Self;
 
     fn mul(mut self, rhs: Self) -> Self::Output {
@@ -211,3 +371,107 @@ impl<'a> std::ops::Mul for RugElement<'a> {
         self
     }
 }
+
+#[cfg(feature = "rug")]
+impl<'a> RugElement<'a> {
+    pub fn is_zero(&self) -> bool {
+        self.element == 0
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.element == rug::Integer::from(1).to_montgomery_unchecked(self.field)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> num_traits::Inv for RugElement<'a> {
+    type Output = Self;
+
+    fn inv(self) -> Self::Output {
+        self.invert()
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> num_traits::Pow<u64> for RugElement<'a> {
+    type Output = Self;
+
+    fn pow(mut self, exponent: u64) -> Self::Output {
+        self.element = self
+            .element
+            .mod_pow(rug::Integer::from(exponent), self.field);
+        self
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> core::ops::Div for RugElement<'a> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "Division by zero");
+        self * rhs.invert()
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> num_traits::CheckedDiv for RugElement<'a> {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        if v.is_zero() {
+            None
+        } else {
+            Some(self.clone() / v.clone())
+        }
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> PartialEq for RugElement<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.element == other.element && self.field.prime == other.field.prime
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> Eq for RugElement<'a> {}
+
+#[cfg(feature = "rug")]
+impl<'a> core::ops::Neg for RugElement<'a> {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        if self.element != 0 {
+            self.element = rug::Integer::from(&self.field.prime - &self.element);
+        }
+        self
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> FieldOps for RugElement<'a> {
+    type Field = RugField;
+
+    fn field(&self) -> &Self::Field {
+        self.field
+    }
+
+    fn zero(&self) -> Self {
+        self.field.zero()
+    }
+
+    fn one(&self) -> Self {
+        self.field.one()
+    }
+
+    fn element_from_u64(&self, value: u64) -> Self {
+        self.field.wrap_element(rug::Integer::from(value))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.element == 0
+    }
+
+    fn pow_u64(&self, exponent: u64) -> Self {
+        num_traits::Pow::pow(self.clone(), exponent)
+    }
+}
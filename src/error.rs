@@ -0,0 +1,28 @@
+//! Errors of the checked element and field constructors
+use core::fmt;
+
+/// The value is not below the modulus, so it is no valid representative of a field element
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRangeError;
+
+impl fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value is not below the modulus")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfRangeError {}
+
+/// The modulus is not an odd prime, so inversion by Fermat's little theorem and the root algorithms do not apply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotPrimeError;
+
+impl fmt::Display for NotPrimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("modulus is not an odd prime")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotPrimeError {}
@@ -0,0 +1,208 @@
+//! Lenstra's elliptic curve method on Montgomery curves
+//!
+//! Each curve `B * y**2 = x**3 + A * x**2 + x` comes from Suyama's parametrisation with `sigma = 6, 7, ...`,
+//! which makes the group order divisible by 12. Points are kept as projective x-coordinates (X : Z),
+//! so the ladder needs neither y nor any inversion, and `(A + 2) / 4` is kept as a fraction as well.
+//! Stage 1 multiplies the starting point by every prime power up to B1 with a Montgomery ladder.
+//! Stage 2 covers one more prime `q = m * D +- j` in (B1, B2] by comparing the giant step `m * D * Q`
+//! with the baby step `j * Q`, accumulating `X_m * Z_j - X_j * Z_m`.
+//! A factor shows up in the gcd of the modulus with Z after stage 1, or with the product after stage 2.
+use alloc::vec::Vec;
+
+use super::pm1::{max_power, GCD_BLOCK};
+use super::{FactorRing, Gcd, PrimeRange};
+#[cfg(feature = "u128")]
+use crate::prime::is_prime_u128;
+#[cfg(feature = "rug")]
+use crate::prime::is_prime_rug;
+#[cfg(feature = "u128")]
+use crate::PrimIntField;
+#[cfg(feature = "rug")]
+use crate::RugField;
+
+/// Stage 2 bound as a multiple of B1 for `ecm_factor`
+const B2_FACTOR: u64 = 100;
+
+#[derive(Clone)]
+struct Point<E> {
+    x: E,
+    z: E,
+}
+
+/// Montgomery curve with `(A + 2) / 4 = a24_num / a24_den`
+struct Curve<E> {
+    a24_num: E,
+    a24_den: E,
+}
+
+impl<E: FactorRing> Curve<E> {
+    fn double(&self, p: &Point<E>) -> Point<E> {
+        let sum = (p.x.clone() + p.z.clone()).square();
+        let difference = (p.x.clone() - p.z.clone()).square();
+        let t = sum.clone() - difference.clone();
+        Point {
+            x: self.a24_den.clone() * sum * difference.clone(),
+            z: t.clone() * (self.a24_den.clone() * difference + self.a24_num.clone() * t),
+        }
+    }
+
+    /// `p + q` from `p - q`
+    fn add(&self, p: &Point<E>, q: &Point<E>, difference: &Point<E>) -> Point<E> {
+        let u = (p.x.clone() - p.z.clone()) * (q.x.clone() + q.z.clone());
+        let v = (p.x.clone() + p.z.clone()) * (q.x.clone() - q.z.clone());
+        Point {
+            x: difference.z.clone() * (u.clone() + v.clone()).square(),
+            z: difference.x.clone() * (u - v).square(),
+        }
+    }
+
+    fn multiply(&self, p: &Point<E>, k: u64) -> Point<E> {
+        if k == 1 {
+            return p.clone();
+        }
+        let mut low = p.clone();
+        let mut high = self.double(p);
+        for i in (0..u64::BITS - 1 - k.leading_zeros()).rev() {
+            if (k >> i) & 1 == 1 {
+                low = self.add(&high, &low, p);
+                high = self.double(&high);
+            } else {
+                high = self.add(&low, &high, p);
+                low = self.double(&low);
+            }
+        }
+        low
+    }
+}
+
+/// One curve with Suyama's parameter `sigma`, `None` also if it hit all factors of the modulus at once
+fn ecm_curve<E: FactorRing>(one: &E, sigma: u64, b1: u64, b2: u64) -> Option<E::Integer> {
+    let sigma = one.element_from_u64(sigma);
+    let u = sigma.square() - one.element_from_u64(5);
+    let v = sigma.double().double();
+    let u_cubed = u.square() * u.clone();
+    let curve = Curve {
+        a24_num: (v.clone() - u.clone()).square()
+            * (v.clone() - u.clone())
+            * (u.double() + u + v.clone()),
+        a24_den: u_cubed.clone() * v.clone() * one.element_from_u64(16),
+    };
+    let mut point = Point {
+        x: u_cubed,
+        z: v.square() * v,
+    };
+    match curve.a24_den.gcd_modulus() {
+        Gcd::One => {}
+        Gcd::Factor(d) => return Some(d),
+        Gcd::Modulus => return None,
+    }
+
+    for q in PrimeRange::new(2, b1) {
+        point = curve.multiply(&point, max_power(q, b1));
+    }
+    match point.z.gcd_modulus() {
+        Gcd::One => {}
+        Gcd::Factor(d) => return Some(d),
+        Gcd::Modulus => return None,
+    }
+
+    // Stage 2 with D around the square root of B2, baby steps j * Q for odd j up to D / 2
+    let mut primes = PrimeRange::new((b1 + 1).max(3), b2).peekable();
+    let first = *primes.peek()?;
+    let d = (b2.isqrt() | 1) + 1;
+    let double = curve.double(&point);
+    let mut baby_steps: Vec<Point<E>> =
+        alloc::vec![point.clone(), curve.add(&double, &point, &point)];
+    while baby_steps.len() <= (d / 2 / 2) as usize {
+        let n = baby_steps.len();
+        let next = curve.add(&baby_steps[n - 1], &double, &baby_steps[n - 2]);
+        baby_steps.push(next);
+    }
+    let giant_step = curve.multiply(&point, d);
+    let mut m = ((first + d / 2) / d).max(1);
+    let mut giant = curve.multiply(&point, m * d);
+    let mut next_giant = curve.multiply(&point, (m + 1) * d);
+    let mut product = one.clone();
+    let mut count = 0;
+    for q in primes {
+        // Primes below the first giant step are baby steps themselves
+        if q <= d / 2 {
+            product = product * baby_steps[(q / 2) as usize].z.clone();
+            continue;
+        }
+        while q > m * d + d / 2 {
+            let following = curve.add(&next_giant, &giant_step, &giant);
+            giant = core::mem::replace(&mut next_giant, following);
+            m += 1;
+        }
+        let j = q.abs_diff(m * d);
+        let baby = &baby_steps[(j / 2) as usize];
+        product = product * (giant.x.clone() * baby.z.clone() - baby.x.clone() * giant.z.clone());
+        count += 1;
+        if count % GCD_BLOCK == 0 {
+            match product.gcd_modulus() {
+                Gcd::One => {}
+                Gcd::Factor(d) => return Some(d),
+                Gcd::Modulus => return None,
+            }
+        }
+    }
+    match product.gcd_modulus() {
+        Gcd::Factor(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn ecm<E: FactorRing>(one: &E, b1: u64, b2: u64, curves: u64) -> Option<E::Integer> {
+    (6..6 + curves).find_map(|sigma| ecm_curve(one, sigma, b1, b2))
+}
+
+#[cfg(feature = "u128")]
+impl PrimIntField<u128> {
+    /// Elliptic curve method on the composite modulus with up to `curves` curves,
+    /// stage 1 bound `b1` and stage 2 bound `b2`, a nontrivial factor if it finds one
+    pub fn ecm(&self, b1: u64, b2: u64, curves: u64) -> Option<u128> {
+        ecm(&self.one(), b1, b2, curves)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Elliptic curve method on the composite modulus with up to `curves` curves,
+    /// stage 1 bound `b1` and stage 2 bound `b2`, a nontrivial factor if it finds one
+    pub fn ecm(&self, b1: u64, b2: u64, curves: u64) -> Option<rug::Integer> {
+        ecm(&self.one(), b1, b2, curves)
+    }
+}
+
+/// Nontrivial factor of `n` by the elliptic curve method with up to `curves` curves,
+/// stage 1 bound `b1` and a stage 2 bound of `100 * b1`. `None` for primes and if the curves did not suffice
+#[cfg(feature = "rug")]
+pub fn ecm_factor(n: &rug::Integer, b1: u64, curves: u64) -> Option<rug::Integer> {
+    if *n < 4 {
+        return None;
+    }
+    if n.is_even() {
+        return Some(rug::Integer::from(2));
+    }
+    if is_prime_rug(n) {
+        return None;
+    }
+    RugField::setup_ring(n.clone()).ecm(b1, b1.saturating_mul(B2_FACTOR), curves)
+}
+
+/// Nontrivial factor of `n` by the elliptic curve method with up to `curves` curves,
+/// stage 1 bound `b1` and a stage 2 bound of `100 * b1`. `None` for primes and if the curves did not suffice
+#[cfg(feature = "u128")]
+pub fn ecm_factor_u128(n: u128, b1: u64, curves: u64) -> Option<u128> {
+    if n < 4 {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(2);
+    }
+    if is_prime_u128(n) {
+        return None;
+    }
+    PrimIntField::<u128>::setup_ring(n).ecm(b1, b1.saturating_mul(B2_FACTOR), curves)
+}
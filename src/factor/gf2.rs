@@ -0,0 +1,264 @@
+//! Dependencies among sparse vectors over GF(2), for the quadratic sieve
+//!
+//! Structured Gaussian elimination shrinks the matrix before the dense step.
+//! A vector with a column no other vector has cannot be part of a dependency and is dropped,
+//! and a column in at most `MAX_MERGE_WEIGHT` vectors is cleared by adding the lightest of them to the others
+//! and dropping it. Each vector left is then the sum of a set of the original ones.
+//! Dense Gaussian elimination finds the dependencies among the far fewer remaining vectors,
+//! which map back to sets of the original ones.
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Columns in at most this many vectors are cleared by merging the vectors
+const MAX_MERGE_WEIGHT: usize = 12;
+
+/// Sum over GF(2) of two sorted sets, sorted again
+fn symmetric_difference(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::with_capacity(a.len() + b.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            Ordering::Less => {
+                sum.push(a[i]);
+                i += 1;
+            }
+            Ordering::Greater => {
+                sum.push(b[j]);
+                j += 1;
+            }
+            Ordering::Equal => {
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    sum.extend_from_slice(&a[i..]);
+    sum.extend_from_slice(&b[j..]);
+    sum
+}
+
+/// A vector of the shrinking matrix, the sum of the original vectors in `sources`
+struct Row {
+    columns: Vec<u32>,
+    sources: Vec<u32>,
+}
+
+struct SparseMatrix {
+    rows: Vec<Option<Row>>,
+    /// Number of rows having each column
+    weights: Vec<usize>,
+    /// Rows having each column, with stale entries of rows that lost it since
+    column_rows: Vec<Vec<u32>>,
+}
+
+impl SparseMatrix {
+    #[allow(clippy::cast_possible_truncation)]
+    fn new(vectors: Vec<Vec<u32>>, columns: usize) -> Self {
+        let mut weights = alloc::vec![0; columns];
+        let mut column_rows = alloc::vec![Vec::new(); columns];
+        for (r, vector) in vectors.iter().enumerate() {
+            for &column in vector {
+                weights[column as usize] += 1;
+                column_rows[column as usize].push(r as u32);
+            }
+        }
+        let rows = vectors
+            .into_iter()
+            .enumerate()
+            .map(|(r, columns)| {
+                Some(Row {
+                    columns,
+                    sources: alloc::vec![r as u32],
+                })
+            })
+            .collect();
+        Self {
+            rows,
+            weights,
+            column_rows,
+        }
+    }
+
+    /// The rows having `column`, clearing out the stale entries
+    #[allow(clippy::cast_possible_truncation)]
+    fn rows_of(&mut self, column: usize) -> Vec<u32> {
+        let rows = &self.rows;
+        let entries = &mut self.column_rows[column];
+        entries.sort_unstable();
+        entries.dedup();
+        entries.retain(|&r| {
+            rows[r as usize]
+                .as_ref()
+                .is_some_and(|row| row.columns.binary_search(&(column as u32)).is_ok())
+        });
+        entries.clone()
+    }
+
+    fn remove(&mut self, r: u32) -> Row {
+        let row = self.rows[r as usize].take().expect("the row is present");
+        for &column in &row.columns {
+            self.weights[column as usize] -= 1;
+        }
+        row
+    }
+
+    fn add(&mut self, r: u32, pivot: &Row) {
+        let row = self.rows[r as usize].as_mut().expect("the row is present");
+        for &column in &pivot.columns {
+            if row.columns.binary_search(&column).is_ok() {
+                self.weights[column as usize] -= 1;
+            } else {
+                self.weights[column as usize] += 1;
+                self.column_rows[column as usize].push(r);
+            }
+        }
+        row.columns = symmetric_difference(&row.columns, &pivot.columns);
+        row.sources = symmetric_difference(&row.sources, &pivot.sources);
+    }
+
+    /// Clear the columns of weight up to `MAX_MERGE_WEIGHT`, the lightest first
+    fn eliminate(&mut self) {
+        for max_weight in 1..=MAX_MERGE_WEIGHT {
+            loop {
+                let mut progress = false;
+                for column in 0..self.weights.len() {
+                    if self.weights[column] == 0 || self.weights[column] > max_weight {
+                        continue;
+                    }
+                    let members = self.rows_of(column);
+                    let pivot = *members
+                        .iter()
+                        .min_by_key(|&&r| {
+                            self.rows[r as usize].as_ref().map(|row| row.columns.len())
+                        })
+                        .expect("the column has rows");
+                    let pivot_row = self.remove(pivot);
+                    for &r in members.iter().filter(|&&r| r != pivot) {
+                        self.add(r, &pivot_row);
+                    }
+                    progress = true;
+                }
+                if !progress {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Up to `limit` sets of the `vectors`, given as sorted column indices below `columns`, which sum to zero
+///
+/// The dense matrix has a row per column left and a bit per remaining vector.
+/// After reduction to row echelon form each vector that got no pivot gives a dependency
+/// together with the pivots of the rows it still appears in.
+pub(crate) fn dependencies(
+    vectors: Vec<Vec<u32>>,
+    columns: usize,
+    limit: usize,
+) -> Vec<Vec<usize>> {
+    let vector_count = vectors.len();
+    let mut sparse = SparseMatrix::new(vectors, columns);
+    sparse.eliminate();
+    let mut dense_columns = alloc::vec![None; columns];
+    let mut count = 0;
+    for (column, &weight) in sparse.weights.iter().enumerate() {
+        if weight > 0 {
+            dense_columns[column] = Some(count);
+            count += 1;
+        }
+    }
+    let rows: Vec<Row> = sparse.rows.into_iter().flatten().collect();
+    let words = rows.len().div_ceil(64);
+    let mut matrix = alloc::vec![alloc::vec![0u64; words]; count];
+    for (r, row) in rows.iter().enumerate() {
+        for &column in &row.columns {
+            let dense = dense_columns[column as usize].expect("the column has weight");
+            matrix[dense][r / 64] |= 1 << (r % 64);
+        }
+    }
+    let mut pivots = Vec::new();
+    for r in 0..rows.len() {
+        let (word, bit) = (r / 64, 1 << (r % 64));
+        let rank = pivots.len();
+        let Some(row) = (rank..matrix.len()).find(|&i| matrix[i][word] & bit != 0) else {
+            continue;
+        };
+        matrix.swap(rank, row);
+        let pivot = core::mem::take(&mut matrix[rank]);
+        for (i, other) in matrix.iter_mut().enumerate() {
+            if i != rank && other[word] & bit != 0 {
+                for (target, source) in other.iter_mut().zip(&pivot) {
+                    *target ^= source;
+                }
+            }
+        }
+        matrix[rank] = pivot;
+        pivots.push(r);
+    }
+    (0..rows.len())
+        .filter(|r| pivots.binary_search(r).is_err())
+        .take(limit)
+        .map(|free| {
+            let (word, bit) = (free / 64, 1 << (free % 64));
+            let mut parity = alloc::vec![false; vector_count];
+            let used = pivots
+                .iter()
+                .zip(&matrix)
+                .filter(|(_, row)| row[word] & bit != 0)
+                .map(|(&pivot, _)| pivot);
+            for r in core::iter::once(free).chain(used) {
+                for &source in &rows[r].sources {
+                    parity[source as usize] ^= true;
+                }
+            }
+            // The remaining vectors are independent sums of the original ones, so the sum is never empty
+            (0..vector_count).filter(|&source| parity[source]).collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dependencies() {
+        // Sparse random vectors with a few dense columns, like the sieve relations
+        let columns = 300;
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut next = |bound: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % bound) as u32
+        };
+        let vectors: Vec<Vec<u32>> = (0..320)
+            .map(|_| {
+                let mut vector: Vec<u32> = (0..6)
+                    .map(|i| if i < 2 { next(8) } else { next(columns) })
+                    .collect();
+                vector.sort_unstable();
+                // Repeated columns cancel out
+                let mut reduced = Vec::new();
+                for column in vector {
+                    if reduced.last() == Some(&column) {
+                        reduced.pop();
+                    } else {
+                        reduced.push(column);
+                    }
+                }
+                reduced
+            })
+            .collect();
+        let found = dependencies(vectors.clone(), columns as usize, 64);
+        assert!(found.len() >= 20);
+        for dependency in found {
+            assert!(!dependency.is_empty());
+            let sum = dependency.iter().fold(Vec::new(), |sum, &v| {
+                symmetric_difference(&sum, &vectors[v])
+            });
+            assert!(sum.is_empty());
+        }
+        assert!(dependencies(Vec::new(), columns as usize, 64).is_empty());
+    }
+}
@@ -0,0 +1,321 @@
+//! Integer factorisation with Montgomery arithmetic
+//!
+//! `factor_u64` and `factor_u128` split off small primes by trial division
+//! and the remaining cofactor with Brent's variant of Pollard's rho,
+//! iterating `x**2 + c` in a `PrimIntField` set up for the odd composite.
+//! The field is only a ring then, but multiplication and subtraction stay valid,
+//! and as R is coprime to the modulus the gcd can be taken of the Montgomery words directly.
+//! Factors are confirmed prime with `crate::prime`.
+//!
+//! For factors of special shape, the fields of a composite modulus also run
+//! Pollard's p - 1 method in [`pm1`] and Williams' p + 1 method in [`pp1`].
+//! The elliptic curve method in [`ecm`] finds factors of 20 digits in larger integers within seconds,
+//! and of 30 digits with B1 = 250 000 in a few hundred curves, about a quarter of an hour in release builds.
+//! Integers of around 40 to 90 digits without such factors are left to the quadratic sieve in [`siqs`].
+use alloc::vec::Vec;
+
+#[cfg(feature = "rug")]
+use crate::element::RugElement;
+use crate::element::{Element, FieldOps, PrimIntElement};
+#[cfg(feature = "u128")]
+use crate::prime::is_prime_u128;
+use crate::prime::is_prime_u64;
+use crate::{p_gcd, PrimIntField};
+
+#[cfg(any(feature = "u128", feature = "rug"))]
+pub mod ecm;
+#[cfg(feature = "rug")]
+mod gf2;
+pub mod pm1;
+pub mod pp1;
+#[cfg(feature = "rug")]
+pub mod siqs;
+
+/// Trial division covers the factors below this bound
+const TRIAL_DIVISION_BOUND: u64 = 1 << 10;
+
+/// Iterations between two gcds of the accumulated product in Brent's rho
+const RHO_BATCH: usize = 128;
+
+/// Outcome of the gcd of an element with the modulus
+pub(crate) enum Gcd<T> {
+    One,
+    Factor(T),
+    Modulus,
+}
+
+/// Elements of a ring modulo a composite, which can look for factors of the modulus
+pub(crate) trait FactorRing: FieldOps {
+    type Integer;
+    /// gcd of the normal representative and the modulus, taken of the Montgomery word as R is coprime to the modulus
+    fn gcd_modulus(&self) -> Gcd<Self::Integer>;
+}
+
+macro_rules! impl_prim_int_factor_ring {
+    ($t:ty) => {
+        impl<'a> FactorRing for PrimIntElement<'a, $t> {
+            type Integer = $t;
+
+            fn gcd_modulus(&self) -> Gcd<$t> {
+                let n = self.field().modulus();
+                match p_gcd(*self.internal(), n) {
+                    1 => Gcd::One,
+                    g if g == n => Gcd::Modulus,
+                    g => Gcd::Factor(g),
+                }
+            }
+        }
+    };
+}
+
+impl_prim_int_factor_ring!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_factor_ring!(u128);
+
+#[cfg(feature = "rug")]
+impl<'a> FactorRing for RugElement<'a> {
+    type Integer = rug::Integer;
+
+    fn gcd_modulus(&self) -> Gcd<rug::Integer> {
+        let g = rug::Integer::from(self.internal().gcd_ref(self.field().modulus()));
+        if g == 1 {
+            Gcd::One
+        } else if g == *self.field().modulus() {
+            Gcd::Modulus
+        } else {
+            Gcd::Factor(g)
+        }
+    }
+}
+
+/// Length of a segment of the prime sieve
+const SIEVE_SEGMENT: usize = 1 << 15;
+
+/// Primes in [low, high] by a segmented sieve of Eratosthenes, using memory for the square root of `high`
+pub(crate) struct PrimeRange {
+    small_primes: Vec<u64>,
+    high: u64,
+    segment: Vec<bool>,
+    segment_low: u64,
+    position: usize,
+}
+
+impl PrimeRange {
+    pub(crate) fn new(low: u64, high: u64) -> Self {
+        let root = high.isqrt() as usize;
+        let mut is_prime = alloc::vec![true; root + 1];
+        let mut small_primes = Vec::new();
+        for i in 2..=root {
+            if is_prime[i] {
+                small_primes.push(i as u64);
+                for multiple in (i * i..=root).step_by(i) {
+                    is_prime[multiple] = false;
+                }
+            }
+        }
+        Self {
+            small_primes,
+            high,
+            segment: Vec::new(),
+            segment_low: low.max(2),
+            position: 0,
+        }
+    }
+
+    fn sieve_segment(&mut self) {
+        let segment_high = self
+            .segment_low
+            .saturating_add(SIEVE_SEGMENT as u64 - 1)
+            .min(self.high);
+        self.segment.clear();
+        self.segment
+            .resize((segment_high - self.segment_low) as usize + 1, true);
+        for &p in &self.small_primes {
+            if p * p > segment_high {
+                break;
+            }
+            let first = (p * p).max(self.segment_low.div_ceil(p) * p);
+            for multiple in (first..=segment_high).step_by(p as usize) {
+                self.segment[(multiple - self.segment_low) as usize] = false;
+            }
+        }
+        self.position = 0;
+    }
+}
+
+impl Iterator for PrimeRange {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            if let Some(&is_prime) = self.segment.get(self.position) {
+                self.position += 1;
+                if is_prime {
+                    return Some(self.segment_low + self.position as u64 - 1);
+                }
+                continue;
+            }
+            self.segment_low = self.segment_low.checked_add(self.segment.len() as u64)?;
+            if self.segment_low > self.high {
+                return None;
+            }
+            self.sieve_segment();
+        }
+    }
+}
+
+/// Sort the prime factors and merge repeated ones into exponents
+fn collect_factors<T: Ord + Copy>(mut primes: Vec<T>) -> Vec<(T, u32)> {
+    primes.sort_unstable();
+    let mut factors: Vec<(T, u32)> = Vec::new();
+    for prime in primes {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == prime => *exponent += 1,
+            _ => factors.push((prime, 1)),
+        }
+    }
+    factors
+}
+
+macro_rules! impl_prim_int_factor {
+    ($t:ty, $is_prime:ident, $rho:ident, $split:ident, $factor:ident, $narrow:expr) => {
+        /// Nontrivial factor of the odd composite `n` by Brent's rho with `x**2 + c`, `None` if the walk collapsed
+        fn $rho<'a>(field: &'a PrimIntField<$t>, c: $t) -> Option<$t> {
+            let n = field.modulus();
+            let c = field.wrap_element(c);
+            let f = |x: PrimIntElement<'a, $t>| x.square() + c;
+            let mut y = field.wrap_element(2);
+            let mut x = y;
+            let mut saved = y;
+            let mut product = field.one();
+            let mut g = 1;
+            let mut r = 1;
+            while g == 1 {
+                x = y;
+                for _ in 0..r {
+                    y = f(y);
+                }
+                let mut k = 0;
+                while k < r && g == 1 {
+                    saved = y;
+                    for _ in 0..RHO_BATCH.min(r - k) {
+                        y = f(y);
+                        product = product * (x - y);
+                    }
+                    g = p_gcd(*product.internal(), n);
+                    k += RHO_BATCH;
+                }
+                r *= 2;
+            }
+            if g == n {
+                // The batch overshot, redo it one gcd at a time
+                loop {
+                    saved = f(saved);
+                    g = p_gcd(*(x - saved).internal(), n);
+                    if g != 1 {
+                        break;
+                    }
+                }
+            }
+            if g == n {
+                None
+            } else {
+                Some(g)
+            }
+        }
+
+        /// Prime factors of `n`, which has no factor below the trial division bound
+        fn $split(n: $t, primes: &mut Vec<$t>) {
+            let mut composites = alloc::vec![n];
+            while let Some(m) = composites.pop() {
+                if m == 1 || $narrow(m, &mut *primes) {
+                    continue;
+                }
+                if $is_prime(m) {
+                    primes.push(m);
+                    continue;
+                }
+                let root = m.isqrt();
+                if root * root == m {
+                    composites.push(root);
+                    composites.push(root);
+                    continue;
+                }
+                let field = PrimIntField::<$t>::setup_ring(m);
+                let d = (1..)
+                    .find_map(|c| $rho(&field, c))
+                    .expect("a composite has a nontrivial factor");
+                composites.push(d);
+                composites.push(m / d);
+            }
+        }
+
+        /// Prime factorisation as sorted `(prime, exponent)` pairs, empty for 0 and 1
+        pub fn $factor(mut n: $t) -> Vec<($t, u32)> {
+            if n == 0 {
+                return Vec::new();
+            }
+            let mut primes = Vec::new();
+            let mut divisor: $t = 2;
+            while divisor < <$t>::from(TRIAL_DIVISION_BOUND) && divisor * divisor <= n {
+                while n % divisor == 0 {
+                    primes.push(divisor);
+                    n /= divisor;
+                }
+                divisor += if divisor == 2 { 1 } else { 2 };
+            }
+            if divisor * divisor > n {
+                if n > 1 {
+                    primes.push(n);
+                }
+            } else {
+                $split(n, &mut primes);
+            }
+            collect_factors(primes)
+        }
+    };
+}
+
+impl_prim_int_factor!(u64, is_prime_u64, rho_u64, split_u64, factor_u64, |_, _| {
+    false
+});
+#[cfg(feature = "u128")]
+impl_prim_int_factor!(
+    u128,
+    is_prime_u128,
+    rho_u128,
+    split_u128,
+    factor_u128,
+    split_narrow_u128
+);
+
+/// Split cofactors below 2**64 with the faster u64 arithmetic
+#[cfg(feature = "u128")]
+fn split_narrow_u128(m: u128, primes: &mut Vec<u128>) -> bool {
+    let Ok(small) = core::convert::TryFrom::try_from(m) else {
+        return false;
+    };
+    let mut small_primes = Vec::new();
+    split_u64(small, &mut small_primes);
+    primes.extend(small_primes.into_iter().map(u128::from));
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prime_range() {
+        let primes: Vec<u64> = PrimeRange::new(0, 100).collect();
+        assert_eq!(primes.len(), 25);
+        assert_eq!(primes[..5], [2, 3, 5, 7, 11]);
+        // Across several segments, compared with the primality test
+        let low = 1_000_000_000;
+        let high = low + 3 * SIEVE_SEGMENT as u64 + 17;
+        let expected: Vec<u64> = (low..=high).filter(|&n| is_prime_u64(n)).collect();
+        assert_eq!(PrimeRange::new(low, high).collect::<Vec<_>>(), expected);
+        assert_eq!(PrimeRange::new(10, 9).next(), None);
+        assert_eq!(PrimeRange::new(97, 97).collect::<Vec<_>>(), [97]);
+    }
+}
@@ -0,0 +1,122 @@
+//! Pollard's p - 1 method
+//!
+//! Stage 1 raises 2 to the product of all prime powers up to B1 in the ring modulo n.
+//! If p - 1 is B1-smooth for a prime factor p, the power is 1 modulo p and a gcd reveals p.
+//! Stage 2 allows one more prime q in (B1, B2], stepping from prime to prime with a table of powers for the gaps.
+//! Stage 1 takes gcds every few primes and retries a block prime by prime when it found all factors at once,
+//! stage 2 gives up in that case.
+use alloc::vec::Vec;
+
+use super::{FactorRing, Gcd, PrimeRange};
+use crate::PrimIntField;
+#[cfg(feature = "rug")]
+use crate::RugField;
+
+/// Primes between two gcds
+pub(crate) const GCD_BLOCK: usize = 64;
+
+/// Largest power of `q` not above `bound`
+pub(crate) fn max_power(q: u64, bound: u64) -> u64 {
+    let mut power = q;
+    while let Some(next) = power.checked_mul(q).filter(|&next| next <= bound) {
+        power = next;
+    }
+    power
+}
+
+fn pm1<E: FactorRing>(one: &E, b1: u64, b2: u64) -> Option<E::Integer> {
+    let mut x = one.element_from_u64(2);
+    let primes: Vec<u64> = PrimeRange::new(2, b1).collect();
+    for block in primes.chunks(GCD_BLOCK) {
+        let saved = x.clone();
+        for &q in block {
+            x = x.pow_u64(max_power(q, b1));
+        }
+        match (x.clone() - one.clone()).gcd_modulus() {
+            Gcd::One => {}
+            Gcd::Factor(d) => return Some(d),
+            Gcd::Modulus => return backtrack(saved, one, block, b1, |x, q| x.pow_u64(q)),
+        }
+    }
+
+    // Stage 2, x**q for consecutive primes q using x**gap for the even gaps between them
+    let mut primes = PrimeRange::new((b1 + 1).max(3), b2);
+    let first = primes.next()?;
+    let mut y = x.pow_u64(first);
+    let mut gap_powers = alloc::vec![one.clone()];
+    let mut product = y.clone() - one.clone();
+    let mut last = first;
+    for (i, q) in primes.enumerate() {
+        let index = ((q - last) / 2) as usize;
+        while gap_powers.len() <= index {
+            let next = gap_powers[gap_powers.len() - 1].clone() * x.square();
+            gap_powers.push(next);
+        }
+        y = y * gap_powers[index].clone();
+        product = product * (y.clone() - one.clone());
+        last = q;
+        if i % GCD_BLOCK == GCD_BLOCK - 1 {
+            match product.gcd_modulus() {
+                Gcd::One => {}
+                Gcd::Factor(d) => return Some(d),
+                Gcd::Modulus => return None,
+            }
+        }
+    }
+    match product.gcd_modulus() {
+        Gcd::Factor(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Redo a stage 1 block one prime at a time, to separate factors it found together.
+/// `step` raises the saved value `x` to a prime, and the gcd is taken of its difference to `identity`
+pub(crate) fn backtrack<E: FactorRing>(
+    mut x: E,
+    identity: &E,
+    block: &[u64],
+    b1: u64,
+    step: impl Fn(&E, u64) -> E,
+) -> Option<E::Integer> {
+    for &q in block {
+        let mut power = q;
+        loop {
+            x = step(&x, q);
+            match (x.clone() - identity.clone()).gcd_modulus() {
+                Gcd::One => {}
+                Gcd::Factor(d) => return Some(d),
+                Gcd::Modulus => return None,
+            }
+            match power.checked_mul(q) {
+                Some(next) if next <= b1 => power = next,
+                _ => break,
+            }
+        }
+    }
+    None
+}
+
+macro_rules! impl_prim_int_pm1 {
+    ($t:ty) => {
+        impl PrimIntField<$t> {
+            /// Pollard's p - 1 method on the composite modulus with stage 1 bound `b1` and stage 2 bound `b2`,
+            /// a nontrivial factor if it finds one. `b2` at most `b1` skips stage 2
+            pub fn pollard_pm1(&self, b1: u64, b2: u64) -> Option<$t> {
+                pm1(&self.one(), b1, b2)
+            }
+        }
+    };
+}
+
+impl_prim_int_pm1!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_pm1!(u128);
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Pollard's p - 1 method on the composite modulus with stage 1 bound `b1` and stage 2 bound `b2`,
+    /// a nontrivial factor if it finds one. `b2` at most `b1` skips stage 2
+    pub fn pollard_pm1(&self, b1: u64, b2: u64) -> Option<rug::Integer> {
+        pm1(&self.one(), b1, b2)
+    }
+}
@@ -0,0 +1,114 @@
+//! Williams' p + 1 method
+//!
+//! With V_k the Lucas sequence for P = A and Q = 1, stage 1 computes V_M(A) for M the product of all prime powers up to B1,
+//! using `V_mn(A) = V_m(V_n(A))` and a Lucas chain per prime power.
+//! If p + 1 is B1-smooth for a prime factor p and A**2 - 4 is no square modulo p, V_M(A) is 2 modulo p.
+//! For a square it finds p - 1 instead, so several starting values A are tried.
+//! Stage 2 walks the odd k in (B1, B2] with `V_k+2 = V_k * V_2 - V_k-2` and accumulates V_k - 2 for prime k.
+//! As in p - 1, stage 1 retries a block prime by prime when it found all factors at once, stage 2 gives up in that case.
+use alloc::vec::Vec;
+
+use super::pm1::{backtrack, max_power, GCD_BLOCK};
+use super::{FactorRing, Gcd, PrimeRange};
+use crate::element::FieldOps;
+use crate::PrimIntField;
+#[cfg(feature = "rug")]
+use crate::RugField;
+
+/// Starting values, with A**2 - 4 being 5, 12 and 32, so squares modulo p for independent primes 5, 3 and 2
+const SEEDS: [u64; 3] = [3, 4, 6];
+
+/// V_k(v) for Q = 1 by a Montgomery ladder on (V_j, V_j+1)
+fn lucas_v<E: FieldOps>(v: &E, k: u64) -> E {
+    let two = v.one().double();
+    let mut low = two.clone();
+    let mut high = v.clone();
+    for i in (0..u64::BITS - k.leading_zeros()).rev() {
+        if (k >> i) & 1 == 1 {
+            low = low * high.clone() - v.clone();
+            high = high.square() - two.clone();
+        } else {
+            high = low.clone() * high - v.clone();
+            low = low.square() - two.clone();
+        }
+    }
+    low
+}
+
+fn pp1<E: FactorRing>(one: &E, seed: u64, b1: u64, b2: u64) -> Option<E::Integer> {
+    let two = one.double();
+    let mut v = one.element_from_u64(seed);
+    let primes: Vec<u64> = PrimeRange::new(2, b1).collect();
+    for block in primes.chunks(GCD_BLOCK) {
+        let saved = v.clone();
+        for &q in block {
+            v = lucas_v(&v, max_power(q, b1));
+        }
+        match (v.clone() - two.clone()).gcd_modulus() {
+            Gcd::One => {}
+            Gcd::Factor(d) => return Some(d),
+            Gcd::Modulus => return backtrack(saved, &two, block, b1, lucas_v),
+        }
+    }
+
+    // Stage 2
+    let mut primes = PrimeRange::new((b1 + 1).max(3), b2).peekable();
+    let first = *primes.peek()?;
+    let v_2 = v.square() - two.clone();
+    let mut previous = lucas_v(&v, first - 2);
+    let mut current = lucas_v(&v, first);
+    let mut product = one.clone();
+    let mut count = 0;
+    let mut k = first;
+    while let Some(&q) = primes.peek() {
+        if k == q {
+            primes.next();
+            product = product * (current.clone() - two.clone());
+            count += 1;
+            if count % GCD_BLOCK == 0 {
+                match product.gcd_modulus() {
+                    Gcd::One => {}
+                    Gcd::Factor(d) => return Some(d),
+                    Gcd::Modulus => return None,
+                }
+            }
+        }
+        let next = current.clone() * v_2.clone() - previous;
+        previous = current;
+        current = next;
+        k += 2;
+    }
+    match product.gcd_modulus() {
+        Gcd::Factor(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn pp1_seeds<E: FactorRing>(one: &E, b1: u64, b2: u64) -> Option<E::Integer> {
+    SEEDS.iter().find_map(|&seed| pp1(one, seed, b1, b2))
+}
+
+macro_rules! impl_prim_int_pp1 {
+    ($t:ty) => {
+        impl PrimIntField<$t> {
+            /// Williams' p + 1 method on the composite modulus with stage 1 bound `b1` and stage 2 bound `b2`,
+            /// a nontrivial factor if it finds one. `b2` at most `b1` skips stage 2
+            pub fn williams_pp1(&self, b1: u64, b2: u64) -> Option<$t> {
+                pp1_seeds(&self.one(), b1, b2)
+            }
+        }
+    };
+}
+
+impl_prim_int_pp1!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_pp1!(u128);
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Williams' p + 1 method on the composite modulus with stage 1 bound `b1` and stage 2 bound `b2`,
+    /// a nontrivial factor if it finds one. `b2` at most `b1` skips stage 2
+    pub fn williams_pp1(&self, b1: u64, b2: u64) -> Option<rug::Integer> {
+        pp1_seeds(&self.one(), b1, b2)
+    }
+}
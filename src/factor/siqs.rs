@@ -0,0 +1,639 @@
+//! Self-initialising quadratic sieve for integers of around 40 to 90 digits
+//!
+//! The sieve collects relations `(A * x + B)**2 - k * n = A * Q(x)` whose right side is smooth over the factor base,
+//! the primes modulo which `k * n` is a square, with the multiplier `k` picked by the Knuth-Schroeppel function.
+//! Every factor base prime gets a `PrimIntField<u64>`, in which the square root of `k * n`, the inverses of `A`
+//! and the roots and steps of the polynomials are computed.
+//! `A` is a product of `s` factor base primes close to `sqrt(2 * k * n) / M` for the sieve interval `[-M, M)`,
+//! and each `A` serves `2**(s - 1)` values of `B`, visited in Gray code order so that the roots only move by a precomputed step.
+//! Values that are smooth but for one large prime are kept, and two of them with the same large prime make a relation.
+//! A set of relations whose exponent vectors sum to zero over GF(2) gives `X**2 = Y**2 mod n`,
+//! and `gcd(X - Y, n)` is a proper factor for about half of them.
+//! The sets are found by structured Gaussian elimination in `gf2`, which leaves a far smaller dense step.
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use core::f64::consts::{LN_2, LOG10_2};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+
+use rug::ops::Pow;
+use rug::Integer;
+
+use super::{factor_u64, gf2, PrimeRange};
+use crate::element::Element;
+use crate::jacobi::jacobi_u64;
+use crate::prime::is_prime_rug;
+use crate::{PrimIntField, Redc};
+
+/// Odd square-free multipliers considered by the Knuth-Schroeppel function
+const MULTIPLIERS: [u32; 20] = [
+    1, 3, 5, 7, 11, 13, 15, 17, 19, 21, 23, 29, 31, 33, 35, 37, 39, 41, 43, 47,
+];
+
+/// Primes up to this bound enter the Knuth-Schroeppel function
+const KNUTH_SCHROEPPEL_BOUND: u64 = 1000;
+
+/// Factor base size and half the sieve interval for integers up to a number of digits, the last row for anything larger
+const PARAMETERS: [(u32, usize, u32); 9] = [
+    (25, 100, 1 << 13),
+    (30, 150, 1 << 14),
+    (40, 300, 1 << 15),
+    (50, 1200, 1 << 15),
+    (60, 3000, 1 << 15),
+    (65, 6000, 1 << 16),
+    (70, 8000, 1 << 16),
+    (80, 35000, 1 << 18),
+    (90, 80000, 1 << 18),
+];
+
+/// Primes below this bound are not sieved but only tried in trial division
+const SMALL_PRIME_BOUND: u32 = 256;
+
+/// Slack in bits on the sieve threshold, for the small primes and 2, which are not sieved, and the rounded logarithms
+const SMALL_PRIME_CORRECTION: f64 = 21.0;
+
+/// Size of the primes of `A` when the factor base has them
+const A_PRIME_SIZE: f64 = 2000.0;
+
+/// Large primes go up to this multiple of the largest factor base prime
+const LARGE_PRIME_MULTIPLIER: u64 = 64;
+
+/// Relations beyond the number of exponent vector columns before the elimination is tried
+const EXTRA_RELATIONS: usize = 32;
+
+/// Dependencies tried for a factor per elimination
+const MAX_DEPENDENCIES: usize = 64;
+
+/// Eliminations with ever more relations before the sieve gives up on dependencies that only give trivial factors
+const MAX_ELIMINATIONS: usize = 16;
+
+/// Random choices of `A` before the sieve gives up on finding one it has not used yet
+const MAX_A_ATTEMPTS: usize = 1 << 12;
+
+/// The high bit of each of 8 sieve values in a word
+const CANDIDATE_BITS: u64 = 0x8080_8080_8080_8080;
+
+/// Exponent vector column of the sign, followed by 2 and the odd factor base primes
+const SIGN_COLUMN: u32 = 0;
+const TWO_COLUMN: u32 = 1;
+const FIRST_ODD_COLUMN: usize = 2;
+
+struct FactorBasePrime {
+    prime: u32,
+    /// Square root of `k * n` modulo the prime
+    root: u32,
+    /// Rounded base 2 logarithm
+    log: u8,
+    field: PrimIntField<u64>,
+}
+
+/// `(A * x + B)**2 = product of the columns * large**2 mod n`
+struct Relation {
+    x: Integer,
+    columns: Vec<u32>,
+    large: u64,
+}
+
+/// The polynomials of one `A`, at the `B` currently visited
+struct Polynomials {
+    a: Integer,
+    /// Factor base indices of the primes of `A`
+    a_indices: Vec<usize>,
+    b: Integer,
+    /// The terms `B_l` whose signed sum is `B`
+    b_terms: Vec<Integer>,
+    /// `2 * B_l / A` modulo each factor base prime, by `l`
+    steps: Vec<Vec<u32>>,
+    /// The two sieve positions `x + M` modulo each factor base prime with `A * x + B = +- root`,
+    /// `None` for the primes of `A`
+    roots: Vec<Option<[u32; 2]>>,
+}
+
+/// Multiplier `k` maximising the expected contribution of the small primes to the smoothness of `k * n`
+fn knuth_schroeppel(n: &Integer) -> u32 {
+    let primes: Vec<u64> = PrimeRange::new(3, KNUTH_SCHROEPPEL_BOUND).collect();
+    let mut best = (f64::NEG_INFINITY, 1);
+    for &k in &MULTIPLIERS {
+        let kn = Integer::from(n * k);
+        let mut score = -0.5 * f64::from(k).ln();
+        score += match kn.mod_u(8) {
+            1 => 2.0 * LN_2,
+            5 => LN_2,
+            _ => 0.5 * LN_2,
+        };
+        for &p in &primes {
+            #[allow(clippy::cast_precision_loss)]
+            let log = (p as f64).ln();
+            if u64::from(k).is_multiple_of(p) {
+                score += log / p as f64;
+            } else if jacobi_u64(u64::from(kn.mod_u(p as u32)), p) == 1 {
+                score += 2.0 * log / (p - 1) as f64;
+            }
+        }
+        if score > best.0 {
+            best = (score, k);
+        }
+    }
+    best.1
+}
+
+/// The first `size` odd primes modulo which `k * n` is a square or zero, all below `bound`
+fn factor_base(kn: &Integer, size: usize, bound: u64) -> Vec<FactorBasePrime> {
+    PrimeRange::new(3, bound)
+        .filter_map(|p| {
+            #[allow(clippy::cast_possible_truncation)]
+            let prime = p as u32;
+            let residue = u64::from(kn.mod_u(prime));
+            if residue != 0 && jacobi_u64(residue, p) != 1 {
+                return None;
+            }
+            let field = p.setup_field();
+            let root = field
+                .wrap_element(residue)
+                .sqrt()
+                .expect("k * n is a square modulo the prime")
+                .to_normal();
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            Some(FactorBasePrime {
+                prime,
+                root: root as u32,
+                log: f64::from(prime).log2().round() as u8,
+                field,
+            })
+        })
+        .take(size)
+        .collect()
+}
+
+/// Xorshift generator for the choice of `A`, seeded the same way every time so the sieve is reproducible
+struct Xorshift(u64);
+
+impl Xorshift {
+    fn below(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        #[allow(clippy::cast_possible_truncation)]
+        {
+            (self.0 % bound as u64) as usize
+        }
+    }
+}
+
+struct Siqs<'a> {
+    n: &'a Integer,
+    kn: Integer,
+    factor_base: Vec<FactorBasePrime>,
+    half_interval: u32,
+    /// Initial sieve value, which reaches the high bit at the threshold
+    sieve_start: u8,
+    large_bound: u64,
+    rng: Xorshift,
+    used: HashSet<Vec<usize>>,
+    relations: Vec<Relation>,
+    partials: HashMap<u64, Relation>,
+}
+
+impl<'a> Siqs<'a> {
+    fn new(
+        n: &'a Integer,
+        kn: Integer,
+        factor_base: Vec<FactorBasePrime>,
+        half_interval: u32,
+    ) -> Self {
+        let largest = factor_base.last().map_or(2, |p| u64::from(p.prime));
+        let large_bound = largest * LARGE_PRIME_MULTIPLIER;
+        // |Q(x)| is at most about M * sqrt(k * n / 2) on the interval
+        #[allow(clippy::cast_precision_loss)]
+        let log_max = f64::from(half_interval).log2() + (kn.significant_bits() as f64 - 1.0) / 2.0;
+        #[allow(clippy::cast_precision_loss)]
+        let threshold = log_max - (large_bound as f64).log2() - SMALL_PRIME_CORRECTION;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let sieve_start = (128.0 - threshold).clamp(0.0, 127.0) as u8;
+        Self {
+            n,
+            kn,
+            factor_base,
+            half_interval,
+            sieve_start,
+            large_bound,
+            rng: Xorshift(0x2545_f491_4f6c_dd1d),
+            used: HashSet::new(),
+            relations: Vec::new(),
+            partials: HashMap::new(),
+        }
+    }
+
+    fn columns(&self) -> usize {
+        self.factor_base.len() + FIRST_ODD_COLUMN
+    }
+
+    fn column_prime(&self, column: usize) -> u32 {
+        if column == TWO_COLUMN as usize {
+            2
+        } else {
+            self.factor_base[column - FIRST_ODD_COLUMN].prime
+        }
+    }
+
+    /// Factor base indices of a new `A` close to `sqrt(2 * k * n) / M`,
+    /// `None` if the factor base has too few primes for it or the choices keep repeating
+    fn choose_a(&mut self) -> Option<Vec<usize>> {
+        let target = Integer::from(Integer::from(&self.kn << 1).sqrt_ref()) / self.half_interval;
+        let log_target = target.to_f64().ln();
+        // Primes dividing k have no two distinct roots to combine into B
+        let candidates: Vec<usize> = (0..self.factor_base.len())
+            .filter(|&j| {
+                self.factor_base[j].prime >= SMALL_PRIME_BOUND && self.factor_base[j].root != 0
+            })
+            .collect();
+        // Two primes of A and two more to pick from at the least
+        if candidates.len() < 4 {
+            return None;
+        }
+        let preferred = f64::from(self.factor_base[candidates[candidates.len() * 2 / 3]].prime)
+            .min(A_PRIME_SIZE);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let s = ((log_target / preferred.ln()).round() as usize).clamp(2, candidates.len() / 2);
+        #[allow(clippy::cast_precision_loss)]
+        let average = (log_target / s as f64).exp();
+        for attempt in 0..MAX_A_ATTEMPTS {
+            // Widen the range of primes while the choices keep repeating
+            #[allow(clippy::cast_precision_loss)]
+            let spread = 2.0 + (attempt / 16) as f64;
+            let pool: Vec<usize> = candidates
+                .iter()
+                .copied()
+                .filter(|&j| {
+                    let p = f64::from(self.factor_base[j].prime);
+                    p >= average / spread && p <= average * spread
+                })
+                .collect();
+            let pool = if pool.len() < s + 2 {
+                candidates.clone()
+            } else {
+                pool
+            };
+            let mut chosen: Vec<usize> = Vec::with_capacity(s);
+            let mut product = Integer::from(1);
+            while chosen.len() < s - 1 {
+                let j = pool[self.rng.below(pool.len())];
+                if !chosen.contains(&j) {
+                    chosen.push(j);
+                    product *= self.factor_base[j].prime;
+                }
+            }
+            // The last prime brings the product closest to the target
+            let remaining = Integer::from(&target / &product).to_f64();
+            let last = candidates
+                .iter()
+                .copied()
+                .filter(|j| !chosen.contains(j))
+                .min_by(|&i, &j| {
+                    let distance =
+                        |index: usize| (f64::from(self.factor_base[index].prime) - remaining).abs();
+                    distance(i).total_cmp(&distance(j))
+                })
+                .expect("the factor base has enough candidates");
+            chosen.push(last);
+            chosen.sort_unstable();
+            if self.used.insert(chosen.clone()) {
+                return Some(chosen);
+            }
+        }
+        None
+    }
+
+    fn polynomials(&mut self) -> Option<Polynomials> {
+        let a_indices = self.choose_a()?;
+        let a: Integer = a_indices
+            .iter()
+            .map(|&j| self.factor_base[j].prime)
+            .product();
+        // B_l = (A / q_l) * gamma with B_l**2 = k * n mod q_l, and B_l = 0 mod the other primes of A
+        let b_terms: Vec<Integer> = a_indices
+            .iter()
+            .map(|&j| {
+                let FactorBasePrime {
+                    prime,
+                    root,
+                    ref field,
+                    ..
+                } = self.factor_base[j];
+                let a_over_q = Integer::from(&a / prime);
+                let inverse = field
+                    .wrap_element(u64::from(a_over_q.mod_u(prime)))
+                    .invert();
+                let mut gamma = (field.wrap_element(u64::from(root)) * inverse).to_normal();
+                if gamma > u64::from(prime / 2) {
+                    gamma = u64::from(prime) - gamma;
+                }
+                a_over_q * gamma
+            })
+            .collect();
+        let b: Integer = b_terms.iter().sum();
+        let mut steps = alloc::vec![alloc::vec![0; self.factor_base.len()]; b_terms.len()];
+        let mut roots = Vec::with_capacity(self.factor_base.len());
+        for (j, prime) in self.factor_base.iter().enumerate() {
+            if a_indices.contains(&j) {
+                roots.push(None);
+                continue;
+            }
+            let field = &prime.field;
+            let a_inverse = field.wrap_element(u64::from(a.mod_u(prime.prime))).invert();
+            for (l, term) in b_terms.iter().enumerate() {
+                let term = field.wrap_element(u64::from(term.mod_u(prime.prime)));
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    steps[l][j] = ((term + term) * a_inverse).to_normal() as u32;
+                }
+            }
+            let b_mod = field.wrap_element(u64::from(b.mod_u(prime.prime)));
+            let root = field.wrap_element(u64::from(prime.root));
+            // Position i of the sieve stands for x = i - M
+            let shift = field.wrap_element(u64::from(self.half_interval));
+            #[allow(clippy::cast_possible_truncation)]
+            roots.push(Some([
+                ((root - b_mod) * a_inverse + shift).to_normal() as u32,
+                ((-root - b_mod) * a_inverse + shift).to_normal() as u32,
+            ]));
+        }
+        Some(Polynomials {
+            a,
+            a_indices,
+            b,
+            b_terms,
+            steps,
+            roots,
+        })
+    }
+
+    /// Switch to the `index`-th `B` in Gray code order, from the one before it
+    fn next_b(&self, polynomials: &mut Polynomials, index: usize) {
+        let l = index.trailing_zeros() as usize + 1;
+        let gray = index ^ (index >> 1);
+        // Bit l - 1 of the Gray code switches the sign of B_l, which moves every root by 2 * B_l / A
+        let subtract = (gray >> (l - 1)) & 1 == 1;
+        let twice = Integer::from(&polynomials.b_terms[l] << 1);
+        if subtract {
+            polynomials.b -= twice;
+        } else {
+            polynomials.b += twice;
+        }
+        for (j, roots) in polynomials.roots.iter_mut().enumerate() {
+            let Some(roots) = roots else {
+                continue;
+            };
+            let p = self.factor_base[j].prime;
+            let step = polynomials.steps[l][j];
+            // Roots and steps are below p, so one subtraction reduces the sum
+            let step = if subtract { step } else { p - step };
+            for root in roots.iter_mut() {
+                *root += step;
+                if *root >= p {
+                    *root -= p;
+                }
+            }
+        }
+    }
+
+    fn sieve(&self, polynomials: &Polynomials, sieve: &mut [u8]) {
+        sieve.fill(self.sieve_start);
+        for (prime, roots) in self.factor_base.iter().zip(&polynomials.roots) {
+            let Some(roots) = roots else {
+                continue;
+            };
+            let p = prime.prime as usize;
+            if p < SMALL_PRIME_BOUND as usize {
+                continue;
+            }
+            let count = if roots[0] == roots[1] { 1 } else { 2 };
+            for &root in &roots[..count] {
+                // Primes beyond the interval may not hit it at all
+                let mut i = root as usize;
+                while i < sieve.len() {
+                    sieve[i] = sieve[i].wrapping_add(prime.log);
+                    i += p;
+                }
+            }
+        }
+    }
+
+    /// The relation at sieve position `i` if `Q(i - M)` is smooth but for at most one large prime
+    fn relation(&self, polynomials: &Polynomials, i: u32) -> Option<Relation> {
+        let x = i64::from(i) - i64::from(self.half_interval);
+        let ax_b = Integer::from(&polynomials.a * x) + &polynomials.b;
+        let mut value = Integer::from(ax_b.square_ref()) - &self.kn;
+        value.div_exact_mut(&polynomials.a);
+        if value == 0 {
+            return None;
+        }
+        let mut columns = Vec::new();
+        if value < 0 {
+            columns.push(SIGN_COLUMN);
+            value = -value;
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        columns.extend(
+            polynomials
+                .a_indices
+                .iter()
+                .map(|&j| (j + FIRST_ODD_COLUMN) as u32),
+        );
+        let twos = value.find_one(0).unwrap_or(0);
+        value >>= twos;
+        columns.extend((0..twos).map(|_| TWO_COLUMN));
+        for (j, (prime, roots)) in self.factor_base.iter().zip(&polynomials.roots).enumerate() {
+            let p = prime.prime;
+            let divides = match roots {
+                Some(roots) if p >= SMALL_PRIME_BOUND => {
+                    let residue = i % p;
+                    residue == roots[0] || residue == roots[1]
+                }
+                _ => value.is_divisible_u(p),
+            };
+            if divides {
+                while value.is_divisible_u(p) {
+                    value.div_exact_u_mut(p);
+                    #[allow(clippy::cast_possible_truncation)]
+                    columns.push((j + FIRST_ODD_COLUMN) as u32);
+                }
+            }
+        }
+        let large = value.to_u64().filter(|&large| large < self.large_bound)?;
+        Some(Relation {
+            x: ax_b % self.n,
+            columns,
+            large,
+        })
+    }
+
+    fn add_relation(&mut self, relation: Relation) {
+        if relation.large == 1 {
+            self.relations.push(relation);
+            return;
+        }
+        match self.partials.entry(relation.large) {
+            Entry::Occupied(other) => {
+                let other = other.get();
+                let mut columns = relation.columns;
+                columns.extend_from_slice(&other.columns);
+                self.relations.push(Relation {
+                    x: (relation.x * &other.x) % self.n,
+                    columns,
+                    large: relation.large,
+                });
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(relation);
+            }
+        }
+    }
+
+    /// Sieve every `B` of a new `A`, false if there is none
+    fn sieve_polynomials(&mut self, sieve: &mut [u8]) -> bool {
+        let Some(mut polynomials) = self.polynomials() else {
+            return false;
+        };
+        for index in 0..1usize << (polynomials.b_terms.len() - 1) {
+            if index > 0 {
+                self.next_b(&mut polynomials, index);
+            }
+            self.sieve(&polynomials, sieve);
+            // Candidates reached the high bit, which a word tests for 8 positions at once
+            for (chunk_index, chunk) in sieve.chunks_exact(8).enumerate() {
+                let word = u64::from_ne_bytes(chunk.try_into().expect("chunks have 8 bytes"));
+                if word & CANDIDATE_BITS == 0 {
+                    continue;
+                }
+                for (offset, &value) in chunk.iter().enumerate() {
+                    if value & 0x80 == 0 {
+                        continue;
+                    }
+                    #[allow(clippy::cast_possible_truncation)]
+                    let i = (chunk_index * 8 + offset) as u32;
+                    if let Some(relation) = self.relation(&polynomials, i) {
+                        self.add_relation(relation);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Sets of relations whose exponent vectors sum to zero over GF(2)
+    fn dependencies(&self) -> Vec<Vec<usize>> {
+        let odd_columns = self
+            .relations
+            .iter()
+            .map(|relation| {
+                let mut columns = relation.columns.clone();
+                columns.sort_unstable();
+                let mut odd = Vec::with_capacity(columns.len());
+                for column in columns {
+                    if odd.last() == Some(&column) {
+                        odd.pop();
+                    } else {
+                        odd.push(column);
+                    }
+                }
+                odd
+            })
+            .collect();
+        gf2::dependencies(odd_columns, self.columns(), MAX_DEPENDENCIES)
+    }
+
+    /// `gcd(X - Y, n)` for a dependency, if it is a proper factor
+    fn square_root(&self, dependency: &[usize]) -> Option<Integer> {
+        let mut x = Integer::from(1);
+        let mut y = Integer::from(1);
+        let mut exponents = alloc::vec![0u32; self.columns()];
+        for &r in dependency {
+            let relation = &self.relations[r];
+            x = (x * &relation.x) % self.n;
+            y = (y * relation.large) % self.n;
+            for &column in &relation.columns {
+                exponents[column as usize] += 1;
+            }
+        }
+        for (column, &exponent) in exponents.iter().enumerate().skip(TWO_COLUMN as usize) {
+            debug_assert!(exponent.is_multiple_of(2));
+            if exponent > 0 {
+                let power = Integer::from(self.column_prime(column))
+                    .pow_mod(&Integer::from(exponent / 2), self.n)
+                    .expect("the exponent is positive");
+                y = (y * power) % self.n;
+            }
+        }
+        let factor = Integer::from(&x - &y).gcd(self.n);
+        (factor != 1 && factor != *self.n).then_some(factor)
+    }
+
+    fn run(mut self) -> Option<Integer> {
+        let mut sieve = alloc::vec![0u8; 2 * self.half_interval as usize];
+        let mut needed = self.columns() + EXTRA_RELATIONS;
+        for _ in 0..MAX_ELIMINATIONS {
+            while self.relations.len() < needed {
+                if !self.sieve_polynomials(&mut sieve) {
+                    return None;
+                }
+            }
+            let factor = self
+                .dependencies()
+                .iter()
+                .find_map(|dependency| self.square_root(dependency));
+            if factor.is_some() {
+                return factor;
+            }
+            needed = self.relations.len() + EXTRA_RELATIONS;
+        }
+        None
+    }
+}
+
+/// Nontrivial factor of `n` by the self-initialising quadratic sieve, `None` for primes and 1,
+/// and if the sieve runs out of new polynomials or its dependencies only give trivial factors.
+/// Integers of up to 64 bits are factored by `factor_u64` instead, and perfect powers give their root
+pub fn siqs_factor(n: &Integer) -> Option<Integer> {
+    if *n < 4 {
+        return None;
+    }
+    if n.is_even() {
+        return Some(Integer::from(2));
+    }
+    if let Some(small) = n.to_u64() {
+        return factor_u64(small)
+            .first()
+            .filter(|&&(p, _)| p != small)
+            .map(|&(p, _)| Integer::from(p));
+    }
+    if is_prime_rug(n) {
+        return None;
+    }
+    if n.is_perfect_power() {
+        return (2..n.significant_bits()).find_map(|e| {
+            let root = Integer::from(n.root_ref(e));
+            (Integer::from((&root).pow(e)) == *n).then_some(root)
+        });
+    }
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let digits = (f64::from(n.significant_bits()) * LOG10_2).ceil() as u32;
+    let (_, size, half_interval) = PARAMETERS
+        .iter()
+        .copied()
+        .find(|&(max_digits, _, _)| digits <= max_digits)
+        .unwrap_or(PARAMETERS[PARAMETERS.len() - 1]);
+    // About twice as many primes as the factor base needs, as half of them are non-residues
+    let bound = 40 * size as u64 + 1000;
+    #[allow(clippy::cast_possible_truncation)]
+    if let Some(p) = PrimeRange::new(3, bound).find(|&p| n.is_divisible_u(p as u32)) {
+        return Some(Integer::from(p));
+    }
+    let kn = Integer::from(n * knuth_schroeppel(n));
+    let factor_base = factor_base(&kn, size, bound);
+    Siqs::new(n, kn, factor_base, half_interval).run()
+}
@@ -0,0 +1,201 @@
+//! Human readable formatting and parsing of elements
+//!
+//! Elements are printed as their normal representative instead of the Montgomery word,
+//! the alternate flag of `Display` (`{:#}`) appends the modulus.
+//! `Debug` shows both, as in `PrimIntElement(5 mod 7)`, and leaves out the field internals.
+//! Parsing accepts decimal and `0x` prefixed hexadecimal numbers with an optional sign,
+//! negative numbers and numbers exceeding the modulus are reduced into the field.
+use core::fmt;
+use core::str::FromStr;
+
+use crate::constant::{ConstPrimeElement, ConstPrimeModulus};
+use crate::element::{Element, FieldOps, PrimIntElement};
+use crate::PrimIntField;
+#[cfg(feature = "rug")]
+use crate::{element::RugElement, RugField};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseElementError {
+    /// No digits after the sign and radix prefix
+    Empty,
+    /// A character that is not a digit in the given radix
+    InvalidDigit,
+}
+
+impl fmt::Display for ParseElementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseElementError::Empty => f.write_str("cannot parse element from empty string"),
+            ParseElementError::InvalidDigit => f.write_str("invalid digit found in string"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseElementError {}
+
+/// Number of digits that always fit into a u64
+fn chunk_digits(radix: u32) -> u32 {
+    if radix == 16 {
+        15
+    } else {
+        19
+    }
+}
+
+/// Parse into the field of `unit`, accumulating the digits in chunks of u64
+fn parse_in_field<E: FieldOps>(unit: &E, s: &str) -> Result<E, ParseElementError> {
+    let (negative, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (radix, digits) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(digits) => (16, digits),
+        None => (10, s),
+    };
+    if digits.is_empty() {
+        return Err(ParseElementError::Empty);
+    }
+
+    let radix_element = unit.element_from_u64(u64::from(radix));
+    let chunk_multiplier = radix_element.pow_u64(u64::from(chunk_digits(radix)));
+    let mut result = unit.zero();
+    let mut chunk = 0u64;
+    let mut chunk_len = 0;
+    for c in digits.chars() {
+        let digit = c.to_digit(radix).ok_or(ParseElementError::InvalidDigit)?;
+        chunk = chunk * u64::from(radix) + u64::from(digit);
+        chunk_len += 1;
+        if chunk_len == chunk_digits(radix) {
+            result = result * chunk_multiplier.clone() + unit.element_from_u64(chunk);
+            chunk = 0;
+            chunk_len = 0;
+        }
+    }
+    if chunk_len != 0 {
+        result =
+            result * radix_element.pow_u64(u64::from(chunk_len)) + unit.element_from_u64(chunk);
+    }
+    Ok(if negative { -result } else { result })
+}
+
+macro_rules! impl_prim_int_fmt {
+    ($t:ty) => {
+        impl PrimIntField<$t> {
+            /// Parse a decimal or `0x` prefixed hexadecimal number, optionally negative, into the field
+            pub fn parse_element(
+                &self,
+                s: &str,
+            ) -> Result<PrimIntElement<'_, $t>, ParseElementError> {
+                parse_in_field(&self.zero(), s)
+            }
+        }
+
+        impl<'a> fmt::Display for PrimIntElement<'a, $t> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.to_normal(), f)?;
+                if f.alternate() {
+                    write!(f, " mod {}", self.field().prime)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl<'a> fmt::Debug for PrimIntElement<'a, $t> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_tuple("PrimIntElement")
+                    .field(&format_args!("{:#}", self))
+                    .finish()
+            }
+        }
+
+        impl<'a> fmt::LowerHex for PrimIntElement<'a, $t> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::LowerHex::fmt(&self.to_normal(), f)
+            }
+        }
+
+        impl<'a> fmt::UpperHex for PrimIntElement<'a, $t> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::UpperHex::fmt(&self.to_normal(), f)
+            }
+        }
+    };
+}
+
+impl_prim_int_fmt!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_fmt!(u128);
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Parse a decimal or `0x` prefixed hexadecimal number, optionally negative, into the field
+    pub fn parse_element(&self, s: &str) -> Result<RugElement<'_>, ParseElementError> {
+        parse_in_field(&self.zero(), s)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> fmt::Display for RugElement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.clone().to_normal(), f)?;
+        if f.alternate() {
+            write!(f, " mod {}", self.field().prime)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> fmt::Debug for RugElement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RugElement")
+            .field(&format_args!("{:#}", self))
+            .finish()
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> fmt::LowerHex for RugElement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.clone().to_normal(), f)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> fmt::UpperHex for RugElement<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.clone().to_normal(), f)
+    }
+}
+
+impl<P: ConstPrimeModulus> FromStr for ConstPrimeElement<P> {
+    type Err = ParseElementError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_in_field(&Self::default(), s)
+    }
+}
+
+impl<P: ConstPrimeModulus> fmt::Display for ConstPrimeElement<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_u64(), f)?;
+        if f.alternate() {
+            write!(f, " mod {}", P::MODULUS)?;
+        }
+        Ok(())
+    }
+}
+
+impl<P: ConstPrimeModulus> fmt::LowerHex for ConstPrimeElement<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&self.to_u64(), f)
+    }
+}
+
+impl<P: ConstPrimeModulus> fmt::UpperHex for ConstPrimeElement<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&self.to_u64(), f)
+    }
+}
@@ -0,0 +1,138 @@
+//! Jacobi and Kronecker symbols, by binary quadratic reciprocity
+//!
+//! The Jacobi symbol (a/n) is defined for odd positive n and agrees with the Legendre symbol for prime n.
+//! Unlike Euler's criterion it needs no exponentiation, and a result of -1 proves `a` is no square modulo `n`
+//! even for composite `n`. The Kronecker symbol extends it to all integers `n`,
+//! with `kronecker_u64` for unsigned moduli of the full width and `kronecker_i64` for signed ones.
+//! The binary algorithm needs only shifts, comparisons and subtractions, no division.
+//! The field methods take the symbol of an element's normal representative over the modulus.
+use crate::element::{Element, PrimIntElement};
+use crate::PrimIntField;
+#[cfg(feature = "rug")]
+use crate::{element::RugElement, RugField};
+
+macro_rules! impl_jacobi {
+    ($name:ident, $t:ty) => {
+        /// Jacobi symbol (a/n), usable in const contexts
+        ///
+        /// # Panics
+        /// Panics if `n` is even
+        pub const fn $name(a: $t, n: $t) -> i32 {
+            assert!(n % 2 == 1, "the Jacobi symbol needs an odd modulus");
+            let mut a = a;
+            let mut n = n;
+            let mut result = 1;
+            while a != 0 {
+                let twos = a.trailing_zeros();
+                a >>= twos;
+                // (2/n) = -1 for n = 3, 5 mod 8
+                if twos % 2 == 1 && (n % 8 == 3 || n % 8 == 5) {
+                    result = -result;
+                }
+                // Both are odd now, reciprocity swaps them so the difference below stays positive
+                if a < n {
+                    (a, n) = (n, a);
+                    if a % 4 == 3 && n % 4 == 3 {
+                        result = -result;
+                    }
+                }
+                a -= n;
+            }
+            if n == 1 {
+                result
+            } else {
+                0
+            }
+        }
+    };
+}
+
+impl_jacobi!(jacobi_u64, u64);
+#[cfg(feature = "u128")]
+impl_jacobi!(jacobi_u128, u128);
+
+macro_rules! impl_kronecker {
+    ($signed:ident, $unsigned:ident, $t:ty, $u:ty, $jacobi:ident) => {
+        /// Kronecker symbol (a/n), for any sign of `a` and any `n`
+        pub const fn $unsigned(a: $t, n: $u) -> i32 {
+            if n == 0 {
+                return if a == 1 || a == -1 { 1 } else { 0 };
+            }
+            let twos = n.trailing_zeros();
+            if twos > 0 && a % 2 == 0 {
+                return 0;
+            }
+            let odd = n >> twos;
+            // (a/2) = -1 for a = 3, 5 mod 8
+            let result = if twos % 2 == 1 && (a & 7 == 3 || a & 7 == 5) {
+                -1
+            } else {
+                1
+            };
+            let reduced = a.unsigned_abs() % odd;
+            let reduced = if a < 0 && reduced != 0 {
+                odd - reduced
+            } else {
+                reduced
+            };
+            result * $jacobi(reduced, odd)
+        }
+
+        /// Kronecker symbol (a/n), for any sign of `a` and `n`
+        pub const fn $signed(a: $t, n: $t) -> i32 {
+            let result = $unsigned(a, n.unsigned_abs());
+            // (a/-1) = -1 for negative a
+            if n < 0 && a < 0 {
+                -result
+            } else {
+                result
+            }
+        }
+    };
+}
+
+impl_kronecker!(kronecker_i64, kronecker_u64, i64, u64, jacobi_u64);
+#[cfg(feature = "u128")]
+impl_kronecker!(kronecker_i128, kronecker_u128, i128, u128, jacobi_u128);
+
+/// Jacobi symbol (a/n) for any `a`
+///
+/// # Panics
+/// Panics if `n` is even or negative
+#[cfg(feature = "rug")]
+pub fn jacobi_rug(a: &rug::Integer, n: &rug::Integer) -> i32 {
+    assert!(
+        n.is_odd() && *n > 0,
+        "the Jacobi symbol needs an odd positive modulus"
+    );
+    a.jacobi(n)
+}
+
+/// Kronecker symbol (a/n), for any sign of `a` and `n`
+#[cfg(feature = "rug")]
+pub fn kronecker_rug(a: &rug::Integer, n: &rug::Integer) -> i32 {
+    a.kronecker(n)
+}
+
+impl PrimIntField<u64> {
+    /// Jacobi symbol of the element over the modulus
+    pub fn jacobi(&self, element: &PrimIntElement<'_, u64>) -> i32 {
+        jacobi_u64(element.to_normal(), self.prime)
+    }
+}
+
+#[cfg(feature = "u128")]
+impl PrimIntField<u128> {
+    /// Jacobi symbol of the element over the modulus
+    pub fn jacobi(&self, element: &PrimIntElement<'_, u128>) -> i32 {
+        jacobi_u128(element.to_normal(), self.prime)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Jacobi symbol of the element over the modulus
+    pub fn jacobi(&self, element: &RugElement<'_>) -> i32 {
+        element.clone().to_normal().jacobi(&self.prime)
+    }
+}
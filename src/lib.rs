@@ -1,7 +1,44 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+pub mod arbitrary;
+#[cfg(feature = "alloc")]
+pub mod batch;
+#[cfg(feature = "num-bigint")]
+pub mod biguint;
+pub mod bytes;
+pub mod constant;
 pub mod element;
-
-use element::{PrimIntElement, RugElement};
+pub mod error;
+#[cfg(feature = "alloc")]
+pub mod factor;
+pub mod fmt;
+pub mod jacobi;
+pub mod lucas;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod prime;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod roots;
+#[cfg(feature = "serde")]
+pub mod serialize;
+pub mod signed;
+pub mod sqrt;
+#[cfg(feature = "crypto-bigint")]
+pub mod uint;
+
+use element::PrimIntElement;
+#[cfg(feature = "rug")]
+use element::RugElement;
+use error::OutOfRangeError;
 use num_traits::{PrimInt, WrappingMul};
+#[cfg(feature = "u128")]
 use twoword::TwoWord;
 pub trait Field<T: Redc> {
     fn redc(&self, value: T::SourceType) -> T;
@@ -18,11 +55,28 @@ pub trait Redc: Sized {
     fn mod_pow(self, exponent: Self, field: &Self::FieldType) -> Self;
 }
 
+/// Binary gcd, `b` needs to be odd so common factors of two cannot be lost
+#[cfg(any(feature = "alloc", feature = "rand"))]
+pub(crate) fn p_gcd<T: PrimInt>(mut a: T, mut b: T) -> T {
+    if a.is_zero() {
+        return b;
+    }
+    while !b.is_zero() {
+        b = b >> b.trailing_zeros() as usize;
+        a = a >> a.trailing_zeros() as usize;
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b = b - a;
+    }
+    a
+}
+
 /// Using hensel lifting to calculate `prime_inverse` for `prime_inverse` * prime = -1 mod R
 /// With R being 2**(bits of T)
 fn p_calc_prime_inverse<T>(prime: T) -> T
 where
-    T: PrimInt + std::fmt::Display + WrappingMul + std::ops::ShlAssign + std::ops::BitOrAssign,
+    T: PrimInt + core::fmt::Display + WrappingMul + core::ops::ShlAssign + core::ops::BitOrAssign,
 {
     let two = T::one() + T::one();
     assert!(
@@ -49,15 +103,94 @@ where
     prime_inv_mod
 }
 
-fn p_calc_r_squared_u64(prime: u64) -> u64 {
+/// `p_calc_prime_inverse` for u64 in a const context, using newton iteration instead of bitwise lifting.
+/// Every prime is its own inverse mod 8, and every iteration doubles the number of correct bits.
+const fn p_calc_prime_inverse_const_u64(prime: u64) -> u64 {
+    assert!(
+        prime & 1 == 1,
+        "Prime needs to be coprime to base 2**x, but is not (cannot be divisible by 2)"
+    );
+    let mut prime_inv = prime;
+    let mut correct_bits = 3;
+    while correct_bits < u64::BITS {
+        prime_inv = prime_inv.wrapping_mul(2u64.wrapping_sub(prime.wrapping_mul(prime_inv)));
+        correct_bits *= 2;
+    }
+    prime_inv.wrapping_neg()
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn p_calc_r_squared_u64(prime: u64) -> u64 {
     let r_mod = ((u64::MAX % prime) + 1) % prime;
-    let r_squared = (<u64 as Redc>::SourceType::from(r_mod)
-        * <u64 as Redc>::SourceType::from(r_mod))
-        % <u64 as Redc>::SourceType::from(prime);
+    let r_squared = (r_mod as u128 * r_mod as u128) % prime as u128;
+    r_squared as u64
+}
+
+/// Smallest quadratic non-residue, only searched for prime = 1 mod 8 where Tonelli-Shanks needs one.
+/// Under GRH the least non-residue is below 2 * ln(prime)**2 < bits**2,
+/// so if the search up to there fails, the modulus is not prime.
+/// Perfect squares have no non-residue at all and are skipped.
+macro_rules! p_find_non_residue {
+    ($name:ident, $jacobi:path, $t:ty) => {
+        const fn $name(prime: $t) -> Option<$t> {
+            if prime % 8 != 1 || prime.isqrt() * prime.isqrt() == prime {
+                return None;
+            }
+            let bits = (<$t>::BITS - prime.leading_zeros()) as $t;
+            let mut candidate = 2;
+            while candidate <= bits * bits {
+                if $jacobi(candidate, prime) == -1 {
+                    return Some(candidate);
+                }
+                candidate += 1;
+            }
+            None
+        }
+    };
+}
+
+p_find_non_residue!(p_find_non_residue_u64, jacobi::jacobi_u64, u64);
+#[cfg(feature = "u128")]
+p_find_non_residue!(p_find_non_residue_u128, jacobi::jacobi_u128, u128);
+
+/// Montgomery reduction for `PrimIntField<u64>`, usable in const contexts
+const fn p_redc_u64(field: &PrimIntField<u64>, value: u128) -> u64 {
+    let prime_bits = u64::MAX.count_ones();
+    let value_mod_r = value % (1 << prime_bits);
+    let value_times_n_prime = value_mod_r * field.prime_inverted as u128;
+    let m = value_times_n_prime % (1 << prime_bits);
+    let m_times_prime = m * (field.prime as u128);
+    let (mut tw, carry) = m_times_prime.overflowing_add(value);
+    tw /= 1 << prime_bits;
+    if carry {
+        tw += 1 << prime_bits;
+    }
+    if tw >= field.prime as u128 {
+        tw -= field.prime as u128;
+    }
     #[allow(clippy::cast_possible_truncation)]
     {
-        r_squared as u64
+        tw as u64
+    }
+}
+
+/// Non-residue and its power to the odd part of prime - 1 in montgomery form for `PrimIntField<u64>`,
+/// usable in const contexts
+const fn p_non_residue_u64(field: &PrimIntField<u64>) -> Option<(u64, u64)> {
+    let Some(non_residue) = p_find_non_residue_u64(field.prime) else {
+        return None;
+    };
+    let mut exponent = (field.prime - 1) >> (field.prime - 1).trailing_zeros();
+    let mut power = p_redc_u64(field, non_residue as u128 * field.r_squared as u128);
+    let mut result = p_redc_u64(field, field.r_squared as u128);
+    while exponent != 0 {
+        if exponent % 2 == 1 {
+            result = p_redc_u64(field, result as u128 * power as u128);
+        }
+        power = p_redc_u64(field, power as u128 * power as u128);
+        exponent >>= 1;
     }
+    Some((non_residue, result))
 }
 
 impl PrimIntField<u64> {
@@ -65,48 +198,89 @@ impl PrimIntField<u64> {
     pub fn wrap_element(&self, element: u64) -> PrimIntElement<'_, u64> {
         PrimIntElement::new(element.to_montgomery(self), self)
     }
+    /// Wrap a word already in montgomery representation, which needs to be below the prime
     pub fn raw_element(&self, element: u64) -> PrimIntElement<'_, u64> {
+        debug_assert!(element < self.prime);
         PrimIntElement::new(element, self)
     }
-}
 
-impl Field<u64> for PrimIntField<u64> {
-    fn redc(&self, value: <u64 as Redc>::SourceType) -> u64 {
-        let prime_bits = u64::MAX.count_ones();
-        let value_mod_r = value % (1 << prime_bits);
-        let value_times_n_prime =
-            value_mod_r * <u64 as Redc>::SourceType::from(self.prime_inverted);
-        let m = value_times_n_prime % (1 << prime_bits);
-        let m_times_prime = m * (<u64 as Redc>::SourceType::from(self.prime));
-        let (mut tw, carry) = m_times_prime.overflowing_add(value);
-        tw /= 1 << prime_bits;
-        if carry {
-            tw += 1 << prime_bits;
+    /// Wrap a word already in montgomery representation, rejecting words not below the prime
+    pub fn try_raw_element(
+        &self,
+        element: u64,
+    ) -> Result<PrimIntElement<'_, u64>, OutOfRangeError> {
+        if element < self.prime {
+            Ok(PrimIntElement::new(element, self))
+        } else {
+            Err(OutOfRangeError)
         }
-        if tw >= <u64 as Redc>::SourceType::from(self.prime) {
-            tw -= <u64 as Redc>::SourceType::from(self.prime);
+    }
+
+    /// Convert a normal representative into montgomery representation, rejecting values not below the prime
+    pub fn try_from_canonical(
+        &self,
+        value: u64,
+    ) -> Result<PrimIntElement<'_, u64>, OutOfRangeError> {
+        if value < self.prime {
+            Ok(PrimIntElement::new(
+                value.to_montgomery_unchecked(self),
+                self,
+            ))
+        } else {
+            Err(OutOfRangeError)
         }
-        #[allow(clippy::cast_possible_truncation)]
-        {
-            tw as u64
+    }
+
+    pub fn zero(&self) -> PrimIntElement<'_, u64> {
+        self.raw_element(0)
+    }
+
+    pub fn one(&self) -> PrimIntElement<'_, u64> {
+        self.raw_element(1.to_montgomery_unchecked(self))
+    }
+
+    /// Same field as `setup_field` creates, but usable in const contexts
+    pub const fn setup_const(prime: u64) -> Self {
+        let mut field = Self {
+            prime,
+            prime_inverted: p_calc_prime_inverse_const_u64(prime),
+            r_squared: p_calc_r_squared_u64(prime),
+            non_residue: None,
+        };
+        field.non_residue = p_non_residue_u64(&field);
+        field
+    }
+
+    /// Same field as `setup_field` creates, but without the Tonelli-Shanks data,
+    /// for the primality tests and factoring methods, which take no square roots
+    pub(crate) fn setup_ring(prime: u64) -> Self {
+        Self {
+            prime,
+            prime_inverted: p_calc_prime_inverse(prime),
+            r_squared: p_calc_r_squared_u64(prime),
+            non_residue: None,
         }
     }
 }
 
+impl Field<u64> for PrimIntField<u64> {
+    fn redc(&self, value: <u64 as Redc>::SourceType) -> u64 {
+        p_redc_u64(self, value)
+    }
+}
+
 impl Redc for u64 {
     type SourceType = u128;
     type FieldType = PrimIntField<Self>;
 
     fn setup_field(self) -> Self::FieldType {
-        Self::FieldType {
-            prime: self,
-            prime_inverted: p_calc_prime_inverse(self),
-            r_squared: p_calc_r_squared_u64(self),
-        }
+        let mut field = PrimIntField::<u64>::setup_ring(self);
+        field.non_residue = p_non_residue_u64(&field);
+        field
     }
 
     fn to_montgomery_unchecked(self, field: &Self::FieldType) -> Self {
-        debug_assert!(self <= field.prime);
+        debug_assert!(self < field.prime);
         field.redc(Self::SourceType::from(self) * Self::SourceType::from(field.r_squared))
     }
 
@@ -120,7 +294,7 @@ impl Redc for u64 {
 
     fn mod_pow(self, mut exponent: Self, field: &Self::FieldType) -> Self {
         let mut power = self;
-        let mut result = if exponent % 2 == 0 {
+        let mut result = if exponent.is_multiple_of(2) {
             1u64.to_montgomery_unchecked(field)
         } else {
             self
@@ -137,6 +311,7 @@ impl Redc for u64 {
     }
 }
 
+#[cfg(feature = "u128")]
 fn p_calc_r_squared_u128(prime: u128) -> u128 {
     let r_mod = ((u128::MAX % prime) + 1) % prime;
     let r_squared =
@@ -144,20 +319,25 @@ fn p_calc_r_squared_u128(prime: u128) -> u128 {
     r_squared.lower
 }
 
+#[cfg(feature = "u128")]
 impl Redc for u128 {
     type SourceType = TwoWord<Self>;
     type FieldType = PrimIntField<Self>;
 
     fn setup_field(self) -> Self::FieldType {
-        Self::FieldType {
-            prime: self,
-            prime_inverted: p_calc_prime_inverse(self),
-            r_squared: p_calc_r_squared_u128(self),
-        }
+        let mut field = PrimIntField::<u128>::setup_ring(self);
+        field.non_residue = p_find_non_residue_u128(self).map(|non_residue| {
+            let odd_part = (self - 1) >> (self - 1).trailing_zeros();
+            let power = non_residue
+                .to_montgomery_unchecked(&field)
+                .mod_pow(odd_part, &field);
+            (non_residue, power)
+        });
+        field
     }
 
     fn to_montgomery_unchecked(self, field: &Self::FieldType) -> Self {
-        debug_assert!(self <= field.prime);
+        debug_assert!(self < field.prime);
         field.redc(Self::SourceType::from(self) * Self::SourceType::from(field.r_squared))
     }
 
@@ -174,7 +354,7 @@ impl Redc for u128 {
 
     fn mod_pow(self, mut exponent: Self, field: &Self::FieldType) -> Self {
         let mut power = self;
-        let mut result = if exponent % 2 == 0 {
+        let mut result = if exponent.is_multiple_of(2) {
             1u128.to_montgomery_unchecked(field)
         } else {
             self
@@ -191,16 +371,66 @@ impl Redc for u128 {
     }
 }
 
+#[cfg(feature = "u128")]
 impl PrimIntField<u128> {
     // Convert to montgomery representation, and use a wrapper to do arithmetic without needing to do manual redc operations
     pub fn wrap_element(&self, element: u128) -> PrimIntElement<'_, u128> {
         PrimIntElement::new(element.to_montgomery(self), self)
     }
+    /// Wrap a word already in montgomery representation, which needs to be below the prime
     pub fn raw_element(&self, element: u128) -> PrimIntElement<'_, u128> {
+        debug_assert!(element < self.prime);
         PrimIntElement::new(element, self)
     }
+
+    /// Wrap a word already in montgomery representation, rejecting words not below the prime
+    pub fn try_raw_element(
+        &self,
+        element: u128,
+    ) -> Result<PrimIntElement<'_, u128>, OutOfRangeError> {
+        if element < self.prime {
+            Ok(PrimIntElement::new(element, self))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Convert a normal representative into montgomery representation, rejecting values not below the prime
+    pub fn try_from_canonical(
+        &self,
+        value: u128,
+    ) -> Result<PrimIntElement<'_, u128>, OutOfRangeError> {
+        if value < self.prime {
+            Ok(PrimIntElement::new(
+                value.to_montgomery_unchecked(self),
+                self,
+            ))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    pub fn zero(&self) -> PrimIntElement<'_, u128> {
+        self.raw_element(0)
+    }
+
+    pub fn one(&self) -> PrimIntElement<'_, u128> {
+        self.raw_element(1.to_montgomery_unchecked(self))
+    }
+
+    /// Same field as `setup_field` creates, but without the Tonelli-Shanks data,
+    /// for the primality tests and factoring methods, which take no square roots
+    pub(crate) fn setup_ring(prime: u128) -> Self {
+        Self {
+            prime,
+            prime_inverted: p_calc_prime_inverse(prime),
+            r_squared: p_calc_r_squared_u128(prime),
+            non_residue: None,
+        }
+    }
 }
 
+#[cfg(feature = "u128")]
 impl Field<u128> for PrimIntField<u128> {
     fn redc(&self, value: <u128 as Redc>::SourceType) -> u128 {
         use num_traits::ops::overflowing::OverflowingAdd;
@@ -221,6 +451,7 @@ impl Field<u128> for PrimIntField<u128> {
     }
 }
 
+#[cfg(feature = "rug")]
 fn rug_calc_prime_inverse(prime: rug::Integer) -> rug::Integer {
     assert!(
         prime.clone() % 2 != 0,
@@ -246,13 +477,88 @@ fn rug_calc_prime_inverse(prime: rug::Integer) -> rug::Integer {
     prime_inv_mod
 }
 
+/// `p_find_non_residue` for rug, together with its power to the odd part of prime - 1 in montgomery form
+#[cfg(feature = "rug")]
+fn rug_non_residue(field: &RugField) -> Option<(rug::Integer, rug::Integer)> {
+    let prime = &field.prime;
+    if prime.mod_u(8) != 1 || prime.is_perfect_square() {
+        return None;
+    }
+    let bits = prime.significant_bits();
+    let non_residue = (2..=bits.saturating_mul(bits))
+        .map(rug::Integer::from)
+        .find(|candidate| candidate.jacobi(prime) == -1)?;
+    let prime_minus_one = rug::Integer::from(prime - 1u32);
+    let two_adicity = prime_minus_one.find_one(0)?;
+    let power = non_residue
+        .clone()
+        .to_montgomery_unchecked(field)
+        .mod_pow(prime_minus_one >> two_adicity, field);
+    Some((non_residue, power))
+}
+
+#[cfg(feature = "rug")]
 impl RugField {
     // Convert to montgomery representation, and use a wrapper to do arithmetic without needing to do manual redc operations
     pub fn wrap_element(&self, element: rug::Integer) -> RugElement<'_> {
         RugElement::new(element.to_montgomery(self), self)
     }
+
+    /// Wrap a value already in montgomery representation, which needs to lie in [0, prime)
+    pub fn raw_element(&self, element: rug::Integer) -> RugElement<'_> {
+        debug_assert!(element >= 0 && element < self.prime);
+        RugElement::new(element, self)
+    }
+
+    /// Wrap a value already in montgomery representation, rejecting values outside of [0, prime)
+    pub fn try_raw_element(
+        &self,
+        element: rug::Integer,
+    ) -> Result<RugElement<'_>, OutOfRangeError> {
+        if element >= 0 && element < self.prime {
+            Ok(RugElement::new(element, self))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    /// Convert a normal representative into montgomery representation, rejecting values outside of [0, prime)
+    pub fn try_from_canonical(
+        &self,
+        value: rug::Integer,
+    ) -> Result<RugElement<'_>, OutOfRangeError> {
+        if value >= 0 && value < self.prime {
+            Ok(RugElement::new(value.to_montgomery_unchecked(self), self))
+        } else {
+            Err(OutOfRangeError)
+        }
+    }
+
+    pub fn zero(&self) -> RugElement<'_> {
+        RugElement::new(rug::Integer::new(), self)
+    }
+
+    pub fn one(&self) -> RugElement<'_> {
+        RugElement::new(rug::Integer::from(1).to_montgomery_unchecked(self), self)
+    }
+
+    /// Same field as `setup_field` creates, but without the Tonelli-Shanks data,
+    /// for the primality tests and factoring methods, which take no square roots
+    pub(crate) fn setup_ring(prime: rug::Integer) -> Self {
+        let r = prime.clone().next_power_of_two();
+        let r_count = r.find_one(0).unwrap();
+        let r_squared = r.square() % &prime;
+        Self {
+            prime: prime.clone(),
+            prime_inverted: rug_calc_prime_inverse(prime),
+            r_squared,
+            r_count,
+            non_residue: None,
+        }
+    }
 }
 
+#[cfg(feature = "rug")]
 impl Field<rug::Integer> for RugField {
     fn redc(&self, value: <rug::Integer as Redc>::SourceType) -> rug::Integer {
         let tw = ((((value.clone().keep_bits(self.r_count)) * &self.prime_inverted)
@@ -268,24 +574,19 @@ impl Field<rug::Integer> for RugField {
     }
 }
 
+#[cfg(feature = "rug")]
 impl Redc for rug::Integer {
     type SourceType = Self;
     type FieldType = RugField;
 
     fn setup_field(self) -> Self::FieldType {
-        let r = self.clone().next_power_of_two();
-        let r_count = r.find_one(0).unwrap();
-        let r_squared = r.square() % &self;
-        Self::FieldType {
-            prime: self.clone(),
-            prime_inverted: rug_calc_prime_inverse(self),
-            r_squared,
-            r_count,
-        }
+        let mut field = RugField::setup_ring(self);
+        field.non_residue = rug_non_residue(&field);
+        field
     }
 
     fn to_montgomery_unchecked(self, field: &Self::FieldType) -> Self {
-        debug_assert!(self <= field.prime);
+        debug_assert!(self < field.prime);
         field.redc(self * &field.r_squared)
     }
 
@@ -316,26 +617,203 @@ impl Redc for rug::Integer {
     }
 }
 
+/// Montgomery field over an arbitrary precision odd modulus
+///
+/// The field is never mutated after `setup_field`, it is `Send` and `Sync`
+/// and can be shared by reference between threads.
+#[cfg(feature = "rug")]
 #[derive(Debug, Clone)]
 pub struct RugField {
     prime: rug::Integer,
     prime_inverted: rug::Integer,
     r_squared: rug::Integer,
     r_count: u32,
+    /// Quadratic non-residue in normal form and its power to the odd part of prime - 1 in montgomery form,
+    /// the Tonelli-Shanks data only present for prime = 1 mod 8
+    non_residue: Option<(rug::Integer, rug::Integer)>,
+}
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// The odd modulus of the field
+    pub fn modulus(&self) -> &rug::Integer {
+        &self.prime
+    }
+
+    /// Number of significant bits of the modulus
+    pub fn bit_length(&self) -> u32 {
+        self.prime.significant_bits()
+    }
+
+    /// R is 2**r_bits, the bit length of the modulus
+    pub fn r_bits(&self) -> u32 {
+        self.r_count
+    }
+
+    /// R itself
+    pub fn r(&self) -> rug::Integer {
+        rug::Integer::from(1) << self.r_count
+    }
+
+    /// R mod prime, which is the montgomery representation of one
+    pub fn r_mod_prime(&self) -> rug::Integer {
+        rug::Integer::from(1).to_montgomery_unchecked(self)
+    }
+
+    /// R**2 mod prime, used to convert into montgomery representation
+    pub fn r_squared(&self) -> &rug::Integer {
+        &self.r_squared
+    }
+
+    /// -prime**-1 mod R, used by the reduction
+    pub fn prime_inverted(&self) -> &rug::Integer {
+        &self.prime_inverted
+    }
+
+    /// The montgomery representation of one, the same as `r_mod_prime`
+    pub fn montgomery_one(&self) -> rug::Integer {
+        self.r_mod_prime()
+    }
+
+    /// Least quadratic non-residue in normal form, precomputed for Tonelli-Shanks if prime = 1 mod 8
+    pub fn non_residue(&self) -> Option<&rug::Integer> {
+        self.non_residue
+            .as_ref()
+            .map(|(non_residue, _)| non_residue)
+    }
+}
+
+/// Fields are equal if they share the modulus, all other values are derived from it
+#[cfg(feature = "rug")]
+impl PartialEq for RugField {
+    fn eq(&self, other: &Self) -> bool {
+        self.prime == other.prime
+    }
+}
+
+#[cfg(feature = "rug")]
+impl Eq for RugField {}
+
+#[cfg(feature = "rug")]
+impl core::hash::Hash for RugField {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.prime.hash(state);
+    }
 }
 
+/// Montgomery field over an odd modulus fitting into a primitive integer
+///
+/// The field is never mutated after `setup_field`, it is `Send` and `Sync`
+/// and can be shared by reference between threads.
 #[derive(Debug, Clone)]
 pub struct PrimIntField<T> {
     prime: T,
     prime_inverted: T,
     r_squared: T,
+    /// Quadratic non-residue in normal form and its power to the odd part of prime - 1 in montgomery form,
+    /// the Tonelli-Shanks data only present for prime = 1 mod 8
+    non_residue: Option<(T, T)>,
+}
+
+impl<T: PrimInt + Redc<FieldType = PrimIntField<T>>> PrimIntField<T> {
+    /// The odd modulus of the field
+    pub fn modulus(&self) -> T {
+        self.prime
+    }
+
+    /// Number of significant bits of the modulus
+    pub fn bit_length(&self) -> u32 {
+        self.r_bits() - self.prime.leading_zeros()
+    }
+
+    /// R is 2**r_bits, the bit width of T
+    pub fn r_bits(&self) -> u32 {
+        T::zero().count_zeros()
+    }
+
+    /// R mod prime, which is the montgomery representation of one
+    pub fn r_mod_prime(&self) -> T {
+        T::one().to_montgomery_unchecked(self)
+    }
+
+    /// R**2 mod prime, used to convert into montgomery representation
+    pub fn r_squared(&self) -> T {
+        self.r_squared
+    }
+
+    /// -prime**-1 mod R, used by the reduction
+    pub fn prime_inverted(&self) -> T {
+        self.prime_inverted
+    }
+
+    /// The montgomery representation of one, the same as `r_mod_prime`
+    pub fn montgomery_one(&self) -> T {
+        self.r_mod_prime()
+    }
+
+    /// Least quadratic non-residue in normal form, precomputed for Tonelli-Shanks if prime = 1 mod 8
+    pub fn non_residue(&self) -> Option<T> {
+        self.non_residue.map(|(non_residue, _)| non_residue)
+    }
+}
+
+/// Fields are equal if they share the modulus, all other values are derived from it
+impl<T: PartialEq> PartialEq for PrimIntField<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.prime == other.prime
+    }
+}
+
+impl<T: Eq> Eq for PrimIntField<T> {}
+
+impl<T: core::hash::Hash> core::hash::Hash for PrimIntField<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.prime.hash(state);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-   use super::p_calc_prime_inverse;
-#[test]
-fn test_prime_inverse() {
-    assert_eq!(p_calc_prime_inverse(23u64), 3_208_129_404_123_400_281);
-}
+    use super::p_calc_prime_inverse;
+    #[test]
+    fn test_prime_inverse() {
+        assert_eq!(p_calc_prime_inverse(23u64), 3_208_129_404_123_400_281);
+    }
+
+    #[test]
+    fn test_prime_inverse_const() {
+        for prime in [1, 3, 23, 9241, 1 << 63 | 1, u64::MAX - 58, u64::MAX] {
+            assert_eq!(
+                super::p_calc_prime_inverse_const_u64(prime),
+                p_calc_prime_inverse(prime)
+            );
+        }
+    }
+
+    #[test]
+    fn test_fields_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<super::PrimIntField<u64>>();
+        #[cfg(feature = "u128")]
+        assert_send_sync::<super::PrimIntField<u128>>();
+        #[cfg(feature = "rug")]
+        assert_send_sync::<super::RugField>();
+    }
+
+    #[test]
+    fn test_setup_ring() {
+        use super::{PrimIntField, Redc};
+        // 17 = 1 mod 8, so only setup_field searches for the non-residue 3
+        let ring = PrimIntField::<u64>::setup_ring(17);
+        assert_eq!(ring.non_residue(), None);
+        assert_eq!(ring, 17u64.setup_field());
+        assert_eq!(17u64.setup_field().non_residue(), Some(3));
+        #[cfg(feature = "u128")]
+        assert_eq!(PrimIntField::<u128>::setup_ring(17).non_residue(), None);
+        #[cfg(feature = "rug")]
+        assert_eq!(
+            super::RugField::setup_ring(rug::Integer::from(17)).non_residue(),
+            None
+        );
+    }
 }
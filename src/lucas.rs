@@ -0,0 +1,109 @@
+//! Lucas sequences U_k(P, Q) and V_k(P, Q) in Montgomery form
+//!
+//! The sequences follow `X_{k+1} = P * X_k - Q * X_{k-1}` with `U_0 = 0, U_1 = 1` and `V_0 = 2, V_1 = P`.
+//! They are computed with a binary ladder over the bits of `k`, doubling by
+//! `U_2k = U_k * V_k` and `V_2k = V_k**2 - 2 * Q**k`, and stepping by
+//! `U_k+1 = (P * U_k + V_k) / 2` and `V_k+1 = (D * U_k + P * V_k) / 2` with `D = P**2 - 4 * Q`.
+//! The halving needs an odd modulus, which every Montgomery field has.
+use crate::element::{FieldOps, PrimIntElement};
+use crate::PrimIntField;
+#[cfg(feature = "rug")]
+use crate::{element::RugElement, RugField};
+
+/// `(U_k, V_k, Q**k)` for `k` given by its bits from the most significant one down
+pub(crate) fn lucas_sequence<E: FieldOps>(
+    p: &E,
+    q: &E,
+    half: &E,
+    bits: impl Iterator<Item = bool>,
+) -> (E, E, E) {
+    let d = p.square() - q.double().double();
+    let mut u = p.zero();
+    let mut v = p.one().double();
+    let mut q_k = p.one();
+    for bit in bits {
+        u = u * v.clone();
+        v = v.square() - q_k.double();
+        q_k = q_k.square();
+        if bit {
+            let next_u = (p.clone() * u.clone() + v.clone()) * half.clone();
+            v = (d.clone() * u + p.clone() * v) * half.clone();
+            u = next_u;
+            q_k = q_k * q.clone();
+        }
+    }
+    (u, v, q_k)
+}
+
+/// Element of a small signed value
+pub(crate) fn element_from_i64<E: FieldOps>(reference: &E, value: i64) -> E {
+    let element = reference.element_from_u64(value.unsigned_abs());
+    if value < 0 {
+        -element
+    } else {
+        element
+    }
+}
+
+macro_rules! impl_prim_int_lucas {
+    ($t:ty) => {
+        impl PrimIntField<$t> {
+            /// `(U_k(P, Q), V_k(P, Q))` modulo the modulus
+            pub fn lucas_uv<'a>(
+                &'a self,
+                p: PrimIntElement<'a, $t>,
+                q: PrimIntElement<'a, $t>,
+                k: $t,
+            ) -> (PrimIntElement<'a, $t>, PrimIntElement<'a, $t>) {
+                let (u, v, _) = self.lucas_sequence(&p, &q, k);
+                (u, v)
+            }
+
+            pub(crate) fn lucas_sequence<'a>(
+                &'a self,
+                p: &PrimIntElement<'a, $t>,
+                q: &PrimIntElement<'a, $t>,
+                k: $t,
+            ) -> (
+                PrimIntElement<'a, $t>,
+                PrimIntElement<'a, $t>,
+                PrimIntElement<'a, $t>,
+            ) {
+                let half = self.wrap_element((self.prime >> 1) + 1);
+                let bits = (0..<$t>::BITS - k.leading_zeros())
+                    .rev()
+                    .map(|i| (k >> i) & 1 == 1);
+                lucas_sequence(p, q, &half, bits)
+            }
+        }
+    };
+}
+
+impl_prim_int_lucas!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_lucas!(u128);
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// `(U_k(P, Q), V_k(P, Q))` modulo the modulus
+    pub fn lucas_uv<'a>(
+        &'a self,
+        p: RugElement<'a>,
+        q: RugElement<'a>,
+        k: &rug::Integer,
+    ) -> (RugElement<'a>, RugElement<'a>) {
+        let (u, v, _) = self.lucas_sequence(&p, &q, k);
+        (u, v)
+    }
+
+    pub(crate) fn lucas_sequence<'a>(
+        &'a self,
+        p: &RugElement<'a>,
+        q: &RugElement<'a>,
+        k: &rug::Integer,
+    ) -> (RugElement<'a>, RugElement<'a>, RugElement<'a>) {
+        let half = self.wrap_element(rug::Integer::from(&self.prime >> 1u32) + 1u32);
+        let bits = (0..k.significant_bits()).rev().map(|i| k.get_bit(i));
+        lucas_sequence(p, q, &half, bits)
+    }
+}
@@ -0,0 +1,103 @@
+//! Batch operations distributed over the rayon thread pool.
+//!
+//! Fields are read-only once set up, so a single field can be shared between all worker threads.
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+
+use rayon::prelude::*;
+
+use crate::batch::batch_invert;
+use crate::element::Element;
+use crate::Redc;
+
+/// Number of elements handled by one Montgomery batch inversion.
+/// Each chunk pays for a single modular exponentiation.
+const INVERSION_CHUNK_SIZE: usize = 1024;
+
+/// Convert every value into montgomery representation
+pub fn par_to_montgomery<T>(values: &[T], field: &T::FieldType) -> Vec<T>
+where
+    T: Redc + Clone + Send + Sync,
+    T::FieldType: Sync,
+{
+    values
+        .par_iter()
+        .map(|x| x.clone().to_montgomery(field))
+        .collect()
+}
+
+/// Convert every value out of montgomery representation
+pub fn par_to_normal<T>(values: &[T], field: &T::FieldType) -> Vec<T>
+where
+    T: Redc + Clone + Send + Sync,
+    T::FieldType: Sync,
+{
+    values
+        .par_iter()
+        .map(|x| x.clone().to_normal(field))
+        .collect()
+}
+
+/// Raise every base, given in montgomery representation, to the same exponent
+pub fn par_mod_pow<T>(bases: &[T], exponent: &T, field: &T::FieldType) -> Vec<T>
+where
+    T: Redc + Clone + Send + Sync,
+    T::FieldType: Sync,
+{
+    bases
+        .par_iter()
+        .map(|x| x.clone().mod_pow(exponent.clone(), field))
+        .collect()
+}
+
+fn par_zip_with<E, F>(lhs: &[E], rhs: &[E], op: F) -> Vec<E>
+where
+    E: Element + Send + Sync,
+    F: Fn(E, E) -> E + Sync,
+{
+    assert_eq!(
+        lhs.len(),
+        rhs.len(),
+        "Elementwise operation on slices of different length"
+    );
+    lhs.par_iter()
+        .zip(rhs.par_iter())
+        .map(|(a, b)| op(a.clone(), b.clone()))
+        .collect()
+}
+
+/// Elementwise sum of two slices of equal length
+pub fn par_add<E>(lhs: &[E], rhs: &[E]) -> Vec<E>
+where
+    E: Element + Add<Output = E> + Send + Sync,
+{
+    par_zip_with(lhs, rhs, |a, b| a + b)
+}
+
+/// Elementwise difference of two slices of equal length
+pub fn par_sub<E>(lhs: &[E], rhs: &[E]) -> Vec<E>
+where
+    E: Element + Sub<Output = E> + Send + Sync,
+{
+    par_zip_with(lhs, rhs, |a, b| a - b)
+}
+
+/// Elementwise product of two slices of equal length
+pub fn par_mul<E>(lhs: &[E], rhs: &[E]) -> Vec<E>
+where
+    E: Element + Mul<Output = E> + Send + Sync,
+{
+    par_zip_with(lhs, rhs, |a, b| a * b)
+}
+
+/// Replace every element by its multiplicative inverse.
+///
+/// All elements need to be invertible, a single zero element zeroes its whole batch.
+pub fn par_batch_invert<E>(elements: &mut [E])
+where
+    E: Element + Mul<Output = E> + Send + Sync,
+{
+    elements
+        .par_chunks_mut(INVERSION_CHUNK_SIZE)
+        .for_each(batch_invert);
+}
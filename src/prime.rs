@@ -0,0 +1,340 @@
+//! Primality testing in Montgomery form
+//!
+//! The tests run in a field set up for the candidate.
+//! For u64 Miller-Rabin with the seven bases of Jim Sinclair is deterministic.
+//! For u128 and `rug::Integer` candidates `is_prime` is the Baillie-PSW test:
+//! a strong probable prime test to base 2 followed by a strong Lucas probable prime test with Selfridge's parameters.
+//! No composite passing it is known, and there is none below 2**64.
+//!
+//! `setup_prime_field` checks the modulus before setting up a field,
+//! as `Element::invert` relies on Fermat's little theorem and is wrong for composite moduli.
+//!
+//! To find primes, `next_prime_u64` and `prev_prime_u64` search from a start value,
+//! and `PrimIntField::ntt_prime` sets up a field for transforms of a given power of two length.
+//! With the `rand` feature, `PrimIntField::random_prime` and `random_safe_prime` draw random primes of a bit length.
+use crate::element::FieldOps;
+use crate::error::NotPrimeError;
+#[cfg(feature = "u128")]
+use crate::jacobi::jacobi_u128;
+use crate::jacobi::jacobi_u64;
+use crate::lucas::element_from_i64;
+#[cfg(feature = "rug")]
+use crate::{element::Element, RugField};
+use crate::{PrimIntField, Redc};
+
+/// Primes for trial division, which also rules out candidates the bases could not test
+const SMALL_PRIMES: [u8; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+
+/// Bases making Miller-Rabin deterministic for all u64
+const MILLER_RABIN_BASES_U64: [u64; 7] = [2, 325, 9375, 28178, 450_775, 9_780_504, 1_795_265_022];
+
+/// Selfridge's D: the first of 5, -7, 9, -11, ... with (D/n) = -1,
+/// `None` if some D shares a factor with `n` and proves it composite
+fn selfridge_parameter(jacobi: impl Fn(u64, bool) -> (i32, bool)) -> Option<i64> {
+    let mut d: i64 = 5;
+    loop {
+        let (symbol, divides) = jacobi(d.unsigned_abs(), d < 0);
+        match symbol {
+            -1 => return Some(d),
+            0 if !divides => return None,
+            _ => {}
+        }
+        d = if d > 0 { -(d + 2) } else { -d + 2 };
+    }
+}
+
+macro_rules! impl_prim_int_prime {
+    ($t:ty, $jacobi:ident) => {
+        impl PrimIntField<$t> {
+            /// Strong probable prime test of the modulus to `base`, bases divisible by the modulus pass
+            pub fn is_strong_probable_prime(&self, base: $t) -> bool {
+                let base = base % self.prime;
+                if base == 0 {
+                    return true;
+                }
+                let one = self.r_mod_prime();
+                let minus_one = self.prime - one;
+                let shift = (self.prime - 1).trailing_zeros();
+                let mut x = base
+                    .to_montgomery(self)
+                    .mod_pow((self.prime - 1) >> shift, self);
+                if x == one || x == minus_one {
+                    return true;
+                }
+                for _ in 1..shift {
+                    x = x.mod_pow(2, self);
+                    if x == minus_one {
+                        return true;
+                    }
+                }
+                false
+            }
+
+            /// Strong Lucas probable prime test of the modulus,
+            /// with Selfridge's D and the parameters P = 1 and Q = (1 - D) / 4
+            pub fn is_strong_lucas_probable_prime(&self) -> bool {
+                let n = self.prime;
+                // The search for D never ends for squares
+                if n.isqrt() * n.isqrt() == n {
+                    return false;
+                }
+                let d = selfridge_parameter(|abs, negative| {
+                    let abs = <$t>::from(abs) % n;
+                    let reduced = if negative && abs != 0 { n - abs } else { abs };
+                    ($jacobi(reduced, n), abs == 0)
+                });
+                let Some(d) = d else {
+                    return false;
+                };
+                let p = self.one();
+                let q = element_from_i64(&p, (1 - d) / 4);
+                // n + 1 = odd * 2**(shift + 1), without overflowing
+                let half = (n >> 1) + 1;
+                let shift = half.trailing_zeros();
+                let (u, mut v, mut q_k) = self.lucas_sequence(&p, &q, half >> shift);
+                if u.is_zero() || v.is_zero() {
+                    return true;
+                }
+                for _ in 0..shift {
+                    v = v.square() - q_k.double();
+                    if v.is_zero() {
+                        return true;
+                    }
+                    q_k = q_k.square();
+                }
+                false
+            }
+
+            /// Trial division by the small primes, `None` if it is inconclusive
+            fn trial_division(&self) -> Option<bool> {
+                if self.prime < 2 {
+                    return Some(false);
+                }
+                for &p in &SMALL_PRIMES {
+                    if self.prime % <$t>::from(p) == 0 {
+                        return Some(self.prime == <$t>::from(p));
+                    }
+                }
+                None
+            }
+        }
+    };
+}
+
+impl_prim_int_prime!(u64, jacobi_u64);
+#[cfg(feature = "u128")]
+impl_prim_int_prime!(u128, jacobi_u128);
+
+impl PrimIntField<u64> {
+    /// Whether the modulus is prime, by trial division and deterministic Miller-Rabin
+    pub fn is_prime(&self) -> bool {
+        self.trial_division().unwrap_or_else(|| {
+            MILLER_RABIN_BASES_U64
+                .iter()
+                .all(|&base| self.is_strong_probable_prime(base))
+        })
+    }
+}
+
+#[cfg(feature = "u128")]
+impl PrimIntField<u128> {
+    /// Whether the modulus is prime, by trial division and Baillie-PSW
+    pub fn is_prime(&self) -> bool {
+        self.trial_division().unwrap_or_else(|| {
+            self.is_strong_probable_prime(2) && self.is_strong_lucas_probable_prime()
+        })
+    }
+}
+
+/// Deterministic primality test
+pub fn is_prime_u64(n: u64) -> bool {
+    if n.is_multiple_of(2) || n < 3 {
+        return n == 2;
+    }
+    PrimIntField::<u64>::setup_ring(n).is_prime()
+}
+
+/// Primality test, deterministic below 2**64 and Baillie-PSW above
+#[cfg(feature = "u128")]
+pub fn is_prime_u128(n: u128) -> bool {
+    if let Ok(n) = core::convert::TryFrom::try_from(n) {
+        return is_prime_u64(n);
+    }
+    n % 2 == 1 && PrimIntField::<u128>::setup_ring(n).is_prime()
+}
+
+/// Baillie-PSW primality test
+#[cfg(feature = "rug")]
+pub fn is_prime_rug(n: &rug::Integer) -> bool {
+    if n.is_even() || *n < 3 {
+        return *n == 2;
+    }
+    RugField::setup_ring(n.clone()).is_prime()
+}
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Strong probable prime test of the modulus to `base`, bases divisible by the modulus pass
+    pub fn is_strong_probable_prime(&self, base: &rug::Integer) -> bool {
+        let base = self.wrap_element(base.clone());
+        if base.is_zero() {
+            return true;
+        }
+        let one = self.one();
+        let minus_one = -self.one();
+        let prime_minus_one = rug::Integer::from(&self.prime - 1u32);
+        let shift = prime_minus_one.find_one(0).unwrap_or(0);
+        let mut x = self.raw_element(
+            base.internal()
+                .clone()
+                .mod_pow(prime_minus_one >> shift, self),
+        );
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..shift {
+            x = x.square();
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Strong Lucas probable prime test of the modulus,
+    /// with Selfridge's D and the parameters P = 1 and Q = (1 - D) / 4
+    pub fn is_strong_lucas_probable_prime(&self) -> bool {
+        let n = &self.prime;
+        if n.is_perfect_square() {
+            return false;
+        }
+        let d = selfridge_parameter(|abs, negative| {
+            let d = if negative {
+                -rug::Integer::from(abs)
+            } else {
+                rug::Integer::from(abs)
+            };
+            (d.jacobi(n), d.is_divisible(n))
+        });
+        let Some(d) = d else {
+            return false;
+        };
+        let p = self.one();
+        let q = element_from_i64(&p, (1 - d) / 4);
+        let n_plus_one = rug::Integer::from(n + 1u32);
+        let shift = n_plus_one.find_one(0).unwrap_or(0);
+        let (u, mut v, mut q_k) = self.lucas_sequence(&p, &q, &(n_plus_one >> shift));
+        if u.is_zero() || v.is_zero() {
+            return true;
+        }
+        for _ in 1..shift {
+            v = v.square() - q_k.double();
+            if v.is_zero() {
+                return true;
+            }
+            q_k = q_k.square();
+        }
+        false
+    }
+
+    /// Whether the modulus is prime, by trial division and Baillie-PSW
+    pub fn is_prime(&self) -> bool {
+        if self.prime < 2 {
+            return false;
+        }
+        for &p in &SMALL_PRIMES {
+            if self.prime.is_divisible_u(u32::from(p)) {
+                return self.prime == p;
+            }
+        }
+        self.is_strong_probable_prime(&rug::Integer::from(2))
+            && self.is_strong_lucas_probable_prime()
+    }
+
+    /// Set up the field after checking the modulus is an odd prime
+    pub fn setup_prime_field(prime: rug::Integer) -> Result<Self, NotPrimeError> {
+        if prime == 2 || !is_prime_rug(&prime) {
+            return Err(NotPrimeError);
+        }
+        Ok(prime.setup_field())
+    }
+}
+
+impl PrimIntField<u64> {
+    /// Set up the field after checking the modulus is an odd prime
+    pub fn setup_prime_field(prime: u64) -> Result<Self, NotPrimeError> {
+        if prime == 2 || !is_prime_u64(prime) {
+            return Err(NotPrimeError);
+        }
+        Ok(prime.setup_field())
+    }
+}
+
+#[cfg(feature = "u128")]
+impl PrimIntField<u128> {
+    /// Set up the field after checking the modulus is an odd prime
+    pub fn setup_prime_field(prime: u128) -> Result<Self, NotPrimeError> {
+        if prime == 2 || !is_prime_u128(prime) {
+            return Err(NotPrimeError);
+        }
+        Ok(prime.setup_field())
+    }
+}
+
+macro_rules! impl_prime_search {
+    ($t:ty, $is_prime:ident, $next:ident, $prev:ident) => {
+        /// Smallest prime above `n`, `None` if there is none in the type
+        pub fn $next(n: $t) -> Option<$t> {
+            if n < 2 {
+                return Some(2);
+            }
+            let mut candidate = n.checked_add(1)? | 1;
+            while !$is_prime(candidate) {
+                candidate = candidate.checked_add(2)?;
+            }
+            Some(candidate)
+        }
+
+        /// Largest prime below `n`, `None` for `n` up to 2
+        pub fn $prev(n: $t) -> Option<$t> {
+            if n <= 3 {
+                return if n == 3 { Some(2) } else { None };
+            }
+            let mut candidate = (n - 2) | 1;
+            while !$is_prime(candidate) {
+                candidate -= 2;
+            }
+            Some(candidate)
+        }
+
+        impl PrimIntField<$t> {
+            /// Field of the largest prime below 2**bits of the form c * 2**two_adicity + 1,
+            /// whose multiplicative group has the roots of unity of order 2**two_adicity a number theoretic transform needs.
+            /// `None` if there is no such prime
+            ///
+            /// # Panics
+            /// Panics if `bits` exceeds the width of the type or is not above `two_adicity`
+            pub fn ntt_prime(two_adicity: u32, bits: u32) -> Option<Self> {
+                assert!(
+                    two_adicity < bits && bits <= <$t>::BITS,
+                    "the prime needs to fit below 2**bits above 2**two_adicity"
+                );
+                // Largest c with c * 2**two_adicity + 1 < 2**bits
+                let max = <$t>::MAX >> (<$t>::BITS - bits);
+                let mut c = (max - 1) >> two_adicity;
+                while c > 0 {
+                    let candidate = (c << two_adicity) + 1;
+                    if $is_prime(candidate) {
+                        return Some(candidate.setup_field());
+                    }
+                    c -= 1;
+                }
+                None
+            }
+        }
+    };
+}
+
+impl_prime_search!(u64, is_prime_u64, next_prime_u64, prev_prime_u64);
+#[cfg(feature = "u128")]
+impl_prime_search!(u128, is_prime_u128, next_prime_u128, prev_prime_u128);
@@ -0,0 +1,150 @@
+//! Uniformly random elements, sampled from any `rand_core::RngCore`
+//!
+//! Values are drawn with the bit length of the modulus and rejected if they are not below it,
+//! so every element is equally likely. As the Montgomery representation is a permutation of [0, prime),
+//! the sampled value is used as the Montgomery word directly, without a conversion.
+//!
+//! Random primes and safe primes of a bit length come with their field set up.
+use rand_core::RngCore;
+
+use crate::element::{Element, PrimIntElement};
+#[cfg(feature = "rug")]
+use crate::{element::RugElement, RugField};
+use crate::{p_gcd, PrimIntField, Redc};
+
+#[cfg(feature = "u128")]
+fn random_u128<R: RngCore + ?Sized>(rng: &mut R) -> u128 {
+    u128::from(rng.next_u64()) | (u128::from(rng.next_u64()) << 64)
+}
+
+macro_rules! impl_prim_int_random {
+    ($t:ty, $next:expr, $is_prime:path) => {
+        impl PrimIntField<$t> {
+            /// Uniformly random element
+            pub fn random<R: RngCore + ?Sized>(&self, rng: &mut R) -> PrimIntElement<'_, $t> {
+                let mask = <$t>::MAX >> self.prime.leading_zeros();
+                loop {
+                    let value = $next(rng) & mask;
+                    if value < self.prime {
+                        return self.raw_element(value);
+                    }
+                }
+            }
+
+            /// Uniformly random element, excluding zero
+            pub fn random_nonzero<R: RngCore + ?Sized>(
+                &self,
+                rng: &mut R,
+            ) -> PrimIntElement<'_, $t> {
+                loop {
+                    let element = self.random(rng);
+                    if !element.is_zero() {
+                        return element;
+                    }
+                }
+            }
+
+            /// Uniformly random element coprime to the modulus, for prime moduli the same as `random_nonzero`
+            pub fn random_invertible<R: RngCore + ?Sized>(
+                &self,
+                rng: &mut R,
+            ) -> PrimIntElement<'_, $t> {
+                // R is coprime to the modulus, so the montgomery word shares its factors with the normal value
+                loop {
+                    let element = self.random_nonzero(rng);
+                    if p_gcd(*element.internal(), self.prime) == 1 {
+                        return element;
+                    }
+                }
+            }
+
+            /// Field of a uniformly random prime with exactly `bits` bits
+            ///
+            /// # Panics
+            /// Panics if `bits` is below 2 or exceeds the width of the type
+            pub fn random_prime<R: RngCore + ?Sized>(bits: u32, rng: &mut R) -> Self {
+                assert!(
+                    (2..=<$t>::BITS).contains(&bits),
+                    "an odd prime needs 2 to {} bits",
+                    <$t>::BITS
+                );
+                loop {
+                    let candidate = Self::random_odd_with_bits(bits, rng);
+                    if $is_prime(candidate) {
+                        return candidate.setup_field();
+                    }
+                }
+            }
+
+            /// Field of a random safe prime p = 2q + 1 with q prime and p having exactly `bits` bits
+            ///
+            /// # Panics
+            /// Panics if `bits` is below 3 or exceeds the width of the type
+            pub fn random_safe_prime<R: RngCore + ?Sized>(bits: u32, rng: &mut R) -> Self {
+                assert!(
+                    (3..=<$t>::BITS).contains(&bits),
+                    "a safe prime needs 3 to {} bits",
+                    <$t>::BITS
+                );
+                loop {
+                    let q = Self::random_odd_with_bits(bits - 1, rng);
+                    let candidate = (q << 1) | 1;
+                    // The cheap trial divisions of p rule out most q before testing either
+                    if $is_prime(candidate) && $is_prime(q) {
+                        return candidate.setup_field();
+                    }
+                }
+            }
+
+            fn random_odd_with_bits<R: RngCore + ?Sized>(bits: u32, rng: &mut R) -> $t {
+                let top = 1 << (bits - 1);
+                ($next(rng) & (top - 1 + top)) | top | 1
+            }
+        }
+    };
+}
+
+impl_prim_int_random!(u64, RngCore::next_u64, crate::prime::is_prime_u64);
+#[cfg(feature = "u128")]
+impl_prim_int_random!(u128, random_u128, crate::prime::is_prime_u128);
+
+#[cfg(feature = "rug")]
+impl RugField {
+    /// Uniformly random element
+    pub fn random<R: RngCore + ?Sized>(&self, rng: &mut R) -> RugElement<'_> {
+        let bits = self.prime.significant_bits();
+        let mut bytes = alloc::vec![0u8; bits.div_ceil(8) as usize];
+        let top_mask = u8::MAX >> ((8 - bits % 8) % 8);
+        loop {
+            rng.fill_bytes(&mut bytes);
+            if let Some(top) = bytes.last_mut() {
+                *top &= top_mask;
+            }
+            let value = rug::Integer::from_digits(&bytes, rug::integer::Order::Lsf);
+            if value < self.prime {
+                return RugElement::new(value, self);
+            }
+        }
+    }
+
+    /// Uniformly random element, excluding zero
+    pub fn random_nonzero<R: RngCore + ?Sized>(&self, rng: &mut R) -> RugElement<'_> {
+        loop {
+            let element = self.random(rng);
+            if *element.internal() != 0 {
+                return element;
+            }
+        }
+    }
+
+    /// Uniformly random element coprime to the modulus, for prime moduli the same as `random_nonzero`
+    pub fn random_invertible<R: RngCore + ?Sized>(&self, rng: &mut R) -> RugElement<'_> {
+        // R is coprime to the modulus, so the montgomery word shares its factors with the normal value
+        loop {
+            let element = self.random_nonzero(rng);
+            if rug::Integer::from(element.internal().gcd_ref(&self.prime)) == 1 {
+                return element;
+            }
+        }
+    }
+}
@@ -0,0 +1,301 @@
+//! k-th roots in prime fields by the Adleman-Manders-Miller method
+//!
+//! Solving `x**k = a` reduces to a `d`-th root with `d = gcd(k, prime - 1)`,
+//! which is extracted one prime factor `r` of `d` at a time.
+//! Each step needs an `r`-th non-residue and discrete logarithms among the `r`-th roots of unity,
+//! found by exhaustive search, so the method is meant for small `k`.
+//! The results are only meaningful for prime moduli.
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use num_traits::PrimInt;
+
+#[cfg(feature = "rug")]
+use crate::element::RugElement;
+use crate::element::{Element, FieldOps, PrimIntElement};
+use crate::Redc;
+
+/// Candidates tried when searching for an r-th non-residue before giving up on the modulus being prime
+const NON_RESIDUE_SEARCH_LIMIT: u64 = 1 << 16;
+
+/// Arithmetic on the order of the multiplicative group, `prime - 1`, with small operands
+trait GroupOrder: FieldOps {
+    type Order: Clone;
+    fn group_order(&self) -> Self::Order;
+    fn pow_order(&self, exponent: &Self::Order) -> Self;
+    fn order_rem(order: &Self::Order, divisor: u64) -> u64;
+    fn order_div(order: &Self::Order, divisor: u64) -> Self::Order;
+    /// `value**-1 mod order`, with `value` coprime to `order`
+    fn order_inverse(value: u64, order: &Self::Order) -> Self::Order;
+}
+
+fn smallest_prime_factor(n: u64) -> u64 {
+    let mut factor = 2;
+    while factor * factor <= n {
+        if n.is_multiple_of(factor) {
+            return factor;
+        }
+        factor += 1;
+    }
+    n
+}
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Inverse of `value` modulo `modulus`, both coprime and below 2**64, by the extended Euclidean algorithm
+fn inverse_u64(value: u64, modulus: u64) -> u64 {
+    let (mut r0, mut r1) = (i128::from(modulus), i128::from(value));
+    let (mut t0, mut t1) = (0i128, 1i128);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    {
+        t0.rem_euclid(i128::from(modulus)) as u64
+    }
+}
+
+/// Inverse of a small `value` modulo a wide `modulus`, without overflowing the modulus type
+///
+/// With `y = modulus**-1 mod value`, `modulus * y = 1 + value * m` and the inverse is `modulus - m`.
+fn small_inverse<T: PrimInt + From<u64>>(value: u64, modulus: T) -> T {
+    if modulus == T::one() {
+        return T::zero();
+    }
+    let value = (<T as From<u64>>::from(value) % modulus).to_u64().unwrap();
+    if value == 1 {
+        return T::one();
+    }
+    let wide_value = <T as From<u64>>::from(value);
+    let quotient = modulus / wide_value;
+    let remainder = (modulus % wide_value).to_u64().unwrap();
+    let y = inverse_u64(remainder, value);
+    #[allow(clippy::cast_possible_truncation)]
+    let carry = ((u128::from(remainder) * u128::from(y) - 1) / u128::from(value)) as u64;
+    modulus - (quotient * <T as From<u64>>::from(y) + <T as From<u64>>::from(carry))
+}
+
+macro_rules! impl_prim_int_group_order {
+    ($t:ty) => {
+        impl<'a> GroupOrder for PrimIntElement<'a, $t> {
+            type Order = $t;
+
+            fn group_order(&self) -> $t {
+                self.field().prime - 1
+            }
+
+            fn pow_order(&self, exponent: &$t) -> Self {
+                let field = self.field();
+                field.raw_element(self.internal().mod_pow(*exponent, field))
+            }
+
+            fn order_rem(order: &$t, divisor: u64) -> u64 {
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    (*order % <$t>::from(divisor)) as u64
+                }
+            }
+
+            fn order_div(order: &$t, divisor: u64) -> $t {
+                *order / <$t>::from(divisor)
+            }
+
+            fn order_inverse(value: u64, order: &$t) -> $t {
+                small_inverse(value, *order)
+            }
+        }
+
+        impl<'a> PrimIntElement<'a, $t> {
+            /// A `k`-th root, `None` if the element is no `k`-th power
+            ///
+            /// # Panics
+            /// Panics if `k` is zero
+            pub fn nth_root(&self, k: u32) -> Option<Self> {
+                nth_root(self, k)
+            }
+
+            /// All `k`-th roots, `gcd(k, prime - 1)` of them for nonzero `k`-th powers
+            ///
+            /// # Panics
+            /// Panics if `k` is zero
+            #[cfg(feature = "alloc")]
+            pub fn all_nth_roots(&self, k: u32) -> Vec<Self> {
+                all_nth_roots(self, k)
+            }
+        }
+    };
+}
+
+impl_prim_int_group_order!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_group_order!(u128);
+
+#[cfg(feature = "rug")]
+impl<'a> GroupOrder for RugElement<'a> {
+    type Order = rug::Integer;
+
+    fn group_order(&self) -> rug::Integer {
+        rug::Integer::from(&self.field().prime - 1u32)
+    }
+
+    fn pow_order(&self, exponent: &rug::Integer) -> Self {
+        let field = self.field();
+        field.raw_element(self.internal().clone().mod_pow(exponent.clone(), field))
+    }
+
+    fn order_rem(order: &rug::Integer, divisor: u64) -> u64 {
+        rug::Integer::from(order % divisor).to_u64_wrapping()
+    }
+
+    fn order_div(order: &rug::Integer, divisor: u64) -> rug::Integer {
+        rug::Integer::from(order / divisor)
+    }
+
+    fn order_inverse(value: u64, order: &rug::Integer) -> rug::Integer {
+        if *order == 1 {
+            return rug::Integer::new();
+        }
+        rug::Integer::from(value)
+            .invert(order)
+            .expect("value is coprime to the order")
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> RugElement<'a> {
+    /// A `k`-th root, `None` if the element is no `k`-th power
+    ///
+    /// # Panics
+    /// Panics if `k` is zero
+    pub fn nth_root(&self, k: u32) -> Option<Self> {
+        nth_root(self, k)
+    }
+
+    /// All `k`-th roots, `gcd(k, prime - 1)` of them for nonzero `k`-th powers
+    ///
+    /// # Panics
+    /// Panics if `k` is zero
+    pub fn all_nth_roots(&self, k: u32) -> Vec<Self> {
+        all_nth_roots(self, k)
+    }
+}
+
+/// Some `x` with `x**((prime - 1) / r) != 1`, `None` if there seems to be none because the modulus is not prime
+fn non_residue<E: GroupOrder>(element: &E, r: u64, order: &E::Order) -> Option<E> {
+    let exponent = E::order_div(order, r);
+    (2..NON_RESIDUE_SEARCH_LIMIT)
+        .map(|candidate| element.element_from_u64(candidate))
+        .find(|candidate| !candidate.is_zero() && !candidate.pow_order(&exponent).is_one())
+}
+
+fn pow_r_times<E: FieldOps>(mut element: E, r: u64, times: u32) -> E {
+    for _ in 0..times {
+        element = element.pow_u64(r);
+    }
+    element
+}
+
+/// An `r`-th root of `delta` for a prime `r` dividing `prime - 1`, `delta` needs to be an `r`-th power
+fn amm<E: GroupOrder>(delta: &E, r: u64, order: &E::Order) -> Option<E> {
+    // order = r**s * t with t coprime to r
+    let mut s = 0;
+    let mut t = order.clone();
+    while E::order_rem(&t, r) == 0 {
+        t = E::order_div(&t, r);
+        s += 1;
+    }
+    let rho = non_residue(delta, r, order)?;
+    let alpha = E::order_inverse(r, &t);
+    let root = delta.pow_order(&alpha);
+    // delta**(r * alpha - 1) lies in the subgroup of order r**(s - 1), which the loop clears
+    let mut b = root.pow_u64(r) * delta.clone().invert();
+    let mut c = rho.pow_order(&t);
+    let unity = pow_r_times(c.clone(), r, s - 1);
+    let mut h = delta.one();
+    for i in 1..s {
+        let d = pow_r_times(b.clone(), r, s - 1 - i);
+        // d = unity**log, brute forced
+        let mut power = delta.one();
+        let mut log = 0;
+        while power != d {
+            power = power * unity.clone();
+            log += 1;
+            if log == r {
+                return None;
+            }
+        }
+        let c_power = c.pow_u64((r - log) % r);
+        b = b * c_power.pow_u64(r);
+        h = h * c_power;
+        c = c.pow_u64(r);
+    }
+    Some(root * h)
+}
+
+fn nth_root<E: GroupOrder>(element: &E, k: u32) -> Option<E> {
+    assert!(k != 0, "the 0th root is undefined");
+    if element.is_zero() {
+        return Some(element.clone());
+    }
+    let order = element.group_order();
+    let k = u64::from(k);
+    let d = gcd_u64(k, E::order_rem(&order, k));
+    let cofactor = E::order_div(&order, d);
+    if !element.pow_order(&cofactor).is_one() {
+        return None;
+    }
+    // x**k = a reduces to y**d = a**e with e = (k / d)**-1 mod (prime - 1) / d,
+    // and every root of unity of order dividing d is a d-th power, so the prime factors can go one at a time
+    let mut root = element.pow_order(&E::order_inverse(k / d, &cofactor));
+    let mut remaining = d;
+    while remaining > 1 {
+        let r = smallest_prime_factor(remaining);
+        root = amm(&root, r, &order)?;
+        remaining /= r;
+    }
+    if root.pow_u64(k) == *element {
+        Some(root)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn all_nth_roots<E: GroupOrder>(element: &E, k: u32) -> Vec<E> {
+    let Some(root) = nth_root(element, k) else {
+        return Vec::new();
+    };
+    if element.is_zero() {
+        return alloc::vec![root];
+    }
+    let order = element.group_order();
+    let d = gcd_u64(u64::from(k), E::order_rem(&order, u64::from(k)));
+    // A primitive d-th root of unity, from a primitive r**e-th root per prime power dividing d
+    let mut unity = element.one();
+    let mut remaining = d;
+    while remaining > 1 {
+        let r = smallest_prime_factor(remaining);
+        let mut prime_power = 1;
+        while remaining.is_multiple_of(r) {
+            remaining /= r;
+            prime_power *= r;
+        }
+        let Some(rho) = non_residue(element, r, &order) else {
+            return Vec::new();
+        };
+        unity = unity * rho.pow_order(&E::order_div(&order, prime_power));
+    }
+    let mut roots = Vec::with_capacity(d as usize);
+    let mut next = root;
+    for _ in 0..d {
+        roots.push(next.clone());
+        next = next * unity.clone();
+    }
+    roots
+}
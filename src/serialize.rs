@@ -0,0 +1,144 @@
+//! serde support for fields and elements
+//!
+//! Fields serialize as their modulus and recompute the Montgomery constants when loaded.
+//! Elements serialize in normal form. As an element cannot exist without its field,
+//! it is deserialized through [`ElementSeed`], which carries a reference to the field:
+//!
+//! ```
+//! use redc::Redc;
+//! use redc::serialize::ElementSeed;
+//! use serde::de::DeserializeSeed;
+//!
+//! let field = 1_000_000_007u64.setup_field();
+//! let json = serde_json::to_string(&field.wrap_element(42)).unwrap();
+//! let mut deserializer = serde_json::Deserializer::from_str(&json);
+//! let element = ElementSeed(&field).deserialize(&mut deserializer).unwrap();
+//! assert_eq!(element, field.wrap_element(42));
+//! ```
+use core::fmt;
+
+use serde::de::{DeserializeSeed, Error};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::constant::{ConstPrimeElement, ConstPrimeModulus};
+use crate::element::{Element, PrimIntElement};
+#[cfg(feature = "rug")]
+use crate::{element::RugElement, RugField};
+use crate::{PrimIntField, Redc};
+
+/// Deserializes an element in normal form into the referenced field
+///
+/// Values outside of [0, prime) are rejected instead of being reduced.
+#[derive(Debug, Clone, Copy)]
+pub struct ElementSeed<'a, F>(pub &'a F);
+
+fn out_of_range<E: Error>(value: impl fmt::Display, prime: impl fmt::Display) -> E {
+    E::custom(format_args!(
+        "element {} is out of range for modulus {}",
+        value, prime
+    ))
+}
+
+fn invalid_modulus<E: Error>(prime: impl fmt::Display) -> E {
+    E::custom(format_args!(
+        "modulus {} is not an odd modulus greater than 1",
+        prime
+    ))
+}
+
+macro_rules! impl_prim_int_serde {
+    ($t:ty) => {
+        impl Serialize for PrimIntField<$t> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.prime.serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for PrimIntField<$t> {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let prime = <$t>::deserialize(deserializer)?;
+                if prime % 2 == 0 || prime == 1 {
+                    return Err(invalid_modulus(prime));
+                }
+                Ok(prime.setup_field())
+            }
+        }
+
+        impl<'a> Serialize for PrimIntElement<'a, $t> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.to_normal().serialize(serializer)
+            }
+        }
+
+        impl<'de, 'a> DeserializeSeed<'de> for ElementSeed<'a, PrimIntField<$t>> {
+            type Value = PrimIntElement<'a, $t>;
+
+            fn deserialize<D: Deserializer<'de>>(
+                self,
+                deserializer: D,
+            ) -> Result<Self::Value, D::Error> {
+                let value = <$t>::deserialize(deserializer)?;
+                self.0
+                    .try_from_canonical(value)
+                    .map_err(|_| out_of_range(value, self.0.prime))
+            }
+        }
+    };
+}
+
+impl_prim_int_serde!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_serde!(u128);
+
+#[cfg(feature = "rug")]
+impl Serialize for RugField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.prime.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'de> Deserialize<'de> for RugField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let prime = rug::Integer::deserialize(deserializer)?;
+        if prime.is_even() || prime <= 1 {
+            return Err(invalid_modulus(prime));
+        }
+        Ok(prime.setup_field())
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'a> Serialize for RugElement<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.clone().to_normal().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "rug")]
+impl<'de, 'a> DeserializeSeed<'de> for ElementSeed<'a, RugField> {
+    type Value = RugElement<'a>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        let value = rug::Integer::deserialize(deserializer)?;
+        self.0
+            .try_from_canonical(value.clone())
+            .map_err(|_| out_of_range(value, &self.0.prime))
+    }
+}
+
+impl<P: ConstPrimeModulus> Serialize for ConstPrimeElement<P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_u64().serialize(serializer)
+    }
+}
+
+impl<'de, P: ConstPrimeModulus> Deserialize<'de> for ConstPrimeElement<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = u64::deserialize(deserializer)?;
+        if value >= P::MODULUS {
+            return Err(out_of_range(value, P::MODULUS));
+        }
+        Ok(Self::new(value))
+    }
+}
@@ -0,0 +1,294 @@
+//! Signed Montgomery arithmetic, as commonly used by lattice and NTT code
+//!
+//! Representatives are kept in (-prime, prime) instead of [0, prime),
+//! which removes the conditional subtraction from every reduction.
+//! To keep sums of two representatives from overflowing, the modulus has to be
+//! smaller than 2**(bits - 2) of the signed type.
+use crate::element::{Element, PrimIntElement};
+use crate::{p_calc_prime_inverse, Field, PrimIntField, Redc};
+
+/// Montgomery field using signed representatives, with R being 2**(bits of T)
+#[derive(Debug, Clone)]
+pub struct SignedField<T> {
+    prime: T,
+    /// prime**-1 mod R, unlike the negated inverse of the unsigned fields
+    prime_inverted: T,
+    r_squared: T,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SignedElement<'a, T> {
+    element: T,
+    field: &'a SignedField<T>,
+}
+
+impl<'a, T> SignedElement<'a, T> {
+    pub fn new(element: T, field: &'a SignedField<T>) -> Self {
+        Self { element, field }
+    }
+}
+
+macro_rules! impl_signed {
+    ($t:ty, $source:ty, $unsigned:ty, $unsigned_source:ty) => {
+        impl SignedField<$t> {
+            // Convert to montgomery representation, and use a wrapper to do arithmetic without needing to do manual redc operations
+            pub fn wrap_element(&self, element: $t) -> SignedElement<'_, $t> {
+                SignedElement::new(element.to_montgomery(self), self)
+            }
+            pub fn raw_element(&self, element: $t) -> SignedElement<'_, $t> {
+                SignedElement::new(element, self)
+            }
+
+            /// Map a normal representative from (-prime, prime) into [-(prime - 1) / 2, (prime - 1) / 2]
+            pub fn to_centered(&self, value: $t) -> $t {
+                let half = self.prime >> 1;
+                if value > half {
+                    value - self.prime
+                } else if value < -half {
+                    value + self.prime
+                } else {
+                    value
+                }
+            }
+
+            /// Decode a little endian normal representative in [0, prime), `None` otherwise
+            pub fn from_bytes_le(
+                &self,
+                bytes: &[u8; core::mem::size_of::<$t>()],
+            ) -> Option<SignedElement<'_, $t>> {
+                self.canonical_element(<$unsigned>::from_le_bytes(*bytes))
+            }
+
+            /// Decode a big endian normal representative in [0, prime), `None` otherwise
+            pub fn from_bytes_be(
+                &self,
+                bytes: &[u8; core::mem::size_of::<$t>()],
+            ) -> Option<SignedElement<'_, $t>> {
+                self.canonical_element(<$unsigned>::from_be_bytes(*bytes))
+            }
+
+            fn canonical_element(&self, value: $unsigned) -> Option<SignedElement<'_, $t>> {
+                #[allow(clippy::cast_sign_loss)]
+                if value < self.prime as $unsigned {
+                    #[allow(clippy::cast_possible_wrap)]
+                    Some(self.raw_element((value as $t).to_montgomery_unchecked(self)))
+                } else {
+                    None
+                }
+            }
+
+            /// Reduce a little endian double width value modulo the prime
+            pub fn from_uniform_bytes(
+                &self,
+                bytes: &[u8; 2 * core::mem::size_of::<$t>()],
+            ) -> SignedElement<'_, $t> {
+                #[allow(clippy::cast_sign_loss)]
+                let value = <$unsigned_source>::from_le_bytes(*bytes)
+                    % <$unsigned_source>::from(self.prime as $unsigned);
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                self.raw_element((value as $t).to_montgomery_unchecked(self))
+            }
+
+            /// Set up the unsigned field with the same modulus
+            pub fn unsigned_field(&self) -> PrimIntField<u64> {
+                #[allow(clippy::cast_sign_loss)]
+                (self.prime as u64).setup_field()
+            }
+
+            /// Move an element of the unsigned field with the same modulus into this field
+            pub fn from_unsigned(&self, element: PrimIntElement<'_, u64>) -> SignedElement<'_, $t> {
+                #[allow(clippy::cast_sign_loss)]
+                {
+                    assert_eq!(
+                        element.field().prime,
+                        self.prime as u64,
+                        "Fields need to share the modulus"
+                    );
+                }
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                self.wrap_element(element.to_normal() as $t)
+            }
+        }
+
+        impl Field<$t> for SignedField<$t> {
+            /// Signed montgomery reduction, for |value| < prime * 2**(bits - 1) the result lies in (-prime, prime)
+            fn redc(&self, value: $source) -> $t {
+                #[allow(clippy::cast_possible_truncation)]
+                let m = (value as $t).wrapping_mul(self.prime_inverted);
+                let tw = (value - <$source>::from(m) * <$source>::from(self.prime)) >> <$t>::BITS;
+                #[allow(clippy::cast_possible_truncation)]
+                {
+                    tw as $t
+                }
+            }
+        }
+
+        impl Redc for $t {
+            type SourceType = $source;
+            type FieldType = SignedField<Self>;
+
+            fn setup_field(self) -> Self::FieldType {
+                assert!(
+                    self > 0 && self < 1 << (<$t>::BITS - 2),
+                    "Prime {} needs to lie in (0, 2**{})",
+                    self,
+                    <$t>::BITS - 2
+                );
+                #[allow(clippy::cast_sign_loss)]
+                let prime = self as $unsigned;
+                let r_mod = ((<$unsigned>::MAX % prime) + 1) % prime;
+                let r_squared = <$unsigned_source>::from(r_mod) * <$unsigned_source>::from(r_mod)
+                    % <$unsigned_source>::from(prime);
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                Self::FieldType {
+                    prime: self,
+                    prime_inverted: p_calc_prime_inverse(prime).wrapping_neg() as $t,
+                    r_squared: r_squared as $t,
+                }
+            }
+
+            fn to_montgomery_unchecked(self, field: &Self::FieldType) -> Self {
+                debug_assert!(-field.prime < self && self < field.prime);
+                field.redc(<$source>::from(self) * <$source>::from(field.r_squared))
+            }
+
+            fn to_montgomery(self, field: &Self::FieldType) -> Self {
+                (self % field.prime).to_montgomery_unchecked(field)
+            }
+
+            fn to_normal(self, field: &Self::FieldType) -> Self {
+                field.redc(<$source>::from(self))
+            }
+
+            fn mod_pow(self, mut exponent: Self, field: &Self::FieldType) -> Self {
+                assert!(exponent >= 0, "Exponent {} cannot be negative", exponent);
+                let mut power = self;
+                let mut result = if exponent % 2 == 0 {
+                    (1 as $t).to_montgomery_unchecked(field)
+                } else {
+                    self
+                };
+                exponent >>= 1;
+                while exponent != 0 {
+                    power = field.redc(<$source>::from(power) * <$source>::from(power));
+                    if exponent % 2 == 1 {
+                        result = field.redc(<$source>::from(result) * <$source>::from(power));
+                    }
+                    exponent >>= 1;
+                }
+                result
+            }
+        }
+
+        impl<'a> SignedElement<'a, $t> {
+            /// Normal representative in [-(prime - 1) / 2, (prime - 1) / 2]
+            pub fn to_centered(self) -> $t {
+                self.field.to_centered(self.to_normal())
+            }
+
+            /// Normal representative in [0, prime)
+            fn to_canonical(self) -> $unsigned {
+                let normal = self.to_normal();
+                #[allow(clippy::cast_sign_loss)]
+                if normal < 0 {
+                    (normal + self.field.prime) as $unsigned
+                } else {
+                    normal as $unsigned
+                }
+            }
+
+            /// Little endian encoding of the normal representative in [0, prime)
+            pub fn to_bytes_le(self) -> [u8; core::mem::size_of::<$t>()] {
+                self.to_canonical().to_le_bytes()
+            }
+
+            /// Big endian encoding of the normal representative in [0, prime)
+            pub fn to_bytes_be(self) -> [u8; core::mem::size_of::<$t>()] {
+                self.to_canonical().to_be_bytes()
+            }
+
+            /// Move this element into the unsigned field with the same modulus
+            pub fn to_unsigned<'b>(self, field: &'b PrimIntField<u64>) -> PrimIntElement<'b, u64> {
+                #[allow(clippy::cast_sign_loss)]
+                {
+                    assert_eq!(
+                        field.prime, self.field.prime as u64,
+                        "Fields need to share the modulus"
+                    );
+                }
+                let normal = self.to_normal();
+                let normal = if normal < 0 {
+                    normal + self.field.prime
+                } else {
+                    normal
+                };
+                #[allow(clippy::cast_sign_loss)]
+                field.wrap_element(normal as u64)
+            }
+        }
+
+        impl<'a> Element for SignedElement<'a, $t> {
+            type UnderlyingType = $t;
+
+            fn invert(mut self) -> Self {
+                self.element = self.element.mod_pow(self.field.prime - 2, self.field);
+                self
+            }
+
+            fn pow(mut self, exponent: Self) -> Self {
+                self.element = self.element.mod_pow(exponent.element, self.field);
+                self
+            }
+
+            fn internal(&self) -> &Self::UnderlyingType {
+                &self.element
+            }
+
+            fn to_normal(self) -> Self::UnderlyingType {
+                self.element.to_normal(self.field)
+            }
+        }
+
+        impl<'a> core::ops::Add for SignedElement<'a, $t> {
+            type Output = Self;
+
+            fn add(mut self, rhs: Self) -> Self::Output {
+                self.element += rhs.element;
+                if self.element >= self.field.prime {
+                    self.element -= self.field.prime;
+                } else if self.element <= -self.field.prime {
+                    self.element += self.field.prime;
+                }
+                self
+            }
+        }
+
+        impl<'a> core::ops::Sub for SignedElement<'a, $t> {
+            type Output = Self;
+
+            fn sub(mut self, rhs: Self) -> Self::Output {
+                self.element -= rhs.element;
+                if self.element >= self.field.prime {
+                    self.element -= self.field.prime;
+                } else if self.element <= -self.field.prime {
+                    self.element += self.field.prime;
+                }
+                self
+            }
+        }
+
+        impl<'a> core::ops::Mul for SignedElement<'a, $t> {
+            type Output = Self;
+
+            fn mul(mut self, rhs: Self) -> Self::Output {
+                self.element = self
+                    .field
+                    .redc(<$source>::from(self.element) * <$source>::from(rhs.element));
+                self
+            }
+        }
+    };
+}
+
+impl_signed!(i32, i64, u32, u64);
+impl_signed!(i64, i128, u64, u128);
@@ -0,0 +1,177 @@
+//! Legendre symbol and square roots in prime fields
+//!
+//! `sqrt` picks the algorithm from the residue of the prime:
+//! a single exponentiation for prime = 3 mod 4, Atkin's method for prime = 5 mod 8,
+//! and Tonelli-Shanks with the non-residue data precomputed at field setup otherwise.
+//! The results are only meaningful for prime moduli.
+use crate::element::{Element, PrimIntElement};
+use crate::Redc;
+#[cfg(feature = "rug")]
+use crate::{element::RugElement, Field};
+
+macro_rules! impl_prim_int_sqrt {
+    ($t:ty) => {
+        impl<'a> PrimIntElement<'a, $t> {
+            /// Legendre symbol by Euler's criterion: 0 for zero, 1 for squares and -1 otherwise
+            pub fn legendre(&self) -> i32 {
+                let field = self.field();
+                let element = *self.internal();
+                if element == 0 {
+                    return 0;
+                }
+                let power = element.mod_pow((field.prime - 1) >> 1, field);
+                if power == field.r_mod_prime() {
+                    1
+                } else {
+                    -1
+                }
+            }
+
+            /// A square root, `None` if the element is no square
+            pub fn sqrt(&self) -> Option<Self> {
+                let field = self.field();
+                let a = *self.internal();
+                if a == 0 {
+                    return Some(*self);
+                }
+                let root = if field.prime % 4 == 3 {
+                    // a**((p + 1) / 4), without overflowing p + 1
+                    a.mod_pow((field.prime >> 2) + 1, field)
+                } else if field.prime % 8 == 5 {
+                    // Atkin: b = (2a)**((p - 5) / 8), i = 2a * b**2, root = a * b * (i - 1)
+                    let two_a = *self + *self;
+                    let b = field.raw_element(two_a.internal().mod_pow(field.prime >> 3, field));
+                    let i = two_a * b * b;
+                    *(*self * b * (i - field.one())).internal()
+                } else {
+                    self.tonelli_shanks()?
+                };
+                let root = field.raw_element(root);
+                if root * root == *self {
+                    Some(root)
+                } else {
+                    None
+                }
+            }
+
+            fn tonelli_shanks(&self) -> Option<$t> {
+                let field = self.field();
+                let one = field.r_mod_prime();
+                let two_adicity = (field.prime - 1).trailing_zeros();
+                let odd_part = (field.prime - 1) >> two_adicity;
+                let (_, mut c) = field.non_residue?;
+
+                let square = |x: $t| x.mod_pow(2, field);
+                let mut t = self.internal().mod_pow(odd_part, field);
+                let mut root = self.internal().mod_pow((odd_part >> 1) + 1, field);
+                let mut m = two_adicity;
+                while t != one {
+                    // Least i with t**(2**i) = 1
+                    let mut i = 0;
+                    let mut t_power = t;
+                    while t_power != one {
+                        t_power = square(t_power);
+                        i += 1;
+                        if i == m {
+                            return None;
+                        }
+                    }
+                    let mut b = c;
+                    for _ in 0..m - i - 1 {
+                        b = square(b);
+                    }
+                    root = *(field.raw_element(root) * field.raw_element(b)).internal();
+                    c = square(b);
+                    t = *(field.raw_element(t) * field.raw_element(c)).internal();
+                    m = i;
+                }
+                Some(root)
+            }
+        }
+    };
+}
+
+impl_prim_int_sqrt!(u64);
+#[cfg(feature = "u128")]
+impl_prim_int_sqrt!(u128);
+
+#[cfg(feature = "rug")]
+impl<'a> RugElement<'a> {
+    /// Legendre symbol by Euler's criterion: 0 for zero, 1 for squares and -1 otherwise
+    pub fn legendre(&self) -> i32 {
+        let field = self.field();
+        if *self.internal() == 0 {
+            return 0;
+        }
+        let exponent = rug::Integer::from(&field.prime - 1u32) >> 1u32;
+        if self.internal().clone().mod_pow(exponent, field) == field.r_mod_prime() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// A square root, `None` if the element is no square
+    pub fn sqrt(&self) -> Option<Self> {
+        let field = self.field();
+        let a = self.internal().clone();
+        if a == 0 {
+            return Some(self.clone());
+        }
+        let root = if field.prime.mod_u(4) == 3 {
+            let exponent = rug::Integer::from(&field.prime >> 2u32) + 1u32;
+            field.raw_element(a.mod_pow(exponent, field))
+        } else if field.prime.mod_u(8) == 5 {
+            let two_a = self.clone() + self.clone();
+            let exponent = rug::Integer::from(&field.prime >> 3u32);
+            let b = field.raw_element(two_a.internal().clone().mod_pow(exponent, field));
+            let i = two_a * b.clone() * b.clone();
+            self.clone() * b * (i - field.one())
+        } else {
+            field.raw_element(self.tonelli_shanks()?)
+        };
+        if root.clone() * root.clone() == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+
+    fn tonelli_shanks(&self) -> Option<rug::Integer> {
+        let field = self.field();
+        let one = field.r_mod_prime();
+        let prime_minus_one = rug::Integer::from(&field.prime - 1u32);
+        let two_adicity = prime_minus_one.find_one(0)?;
+        let odd_part = prime_minus_one >> two_adicity;
+        let mut c = field.non_residue.as_ref()?.1.clone();
+
+        let square = |x: rug::Integer| field.redc(rug::Integer::from(x.square_ref()));
+        let mul = |x: rug::Integer, y: &rug::Integer| field.redc(x * y);
+        let mut t = self.internal().clone().mod_pow(odd_part.clone(), field);
+        let mut root = self
+            .internal()
+            .clone()
+            .mod_pow((odd_part >> 1u32) + 1u32, field);
+        let mut m = two_adicity;
+        while t != one {
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while t_power != one {
+                t_power = square(t_power);
+                i += 1;
+                if i == m {
+                    return None;
+                }
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = square(b);
+            }
+            root = mul(root, &b);
+            c = square(b);
+            t = mul(t, &c);
+            m = i;
+        }
+        Some(root)
+    }
+}
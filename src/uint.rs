@@ -0,0 +1,244 @@
+//! Fixed size backend built on `crypto-bigint`
+//!
+//! Reductions, additions and exponentiations run in constant time with respect to the elements
+//! and the exponent, only the field setup branches on the (public) modulus.
+use ::crypto_bigint::subtle::{Choice, ConditionallySelectable};
+use ::crypto_bigint::{Encoding, Limb, Uint};
+
+use crate::element::Element;
+use crate::{Field, Redc};
+
+/// Montgomery field over an odd modulus fitting into `LIMBS` limbs, with R being 2**(bits of the `Uint`)
+///
+/// The field is never mutated after `setup_field`, it is `Send` and `Sync`
+/// and can be shared by reference between threads.
+#[derive(Debug, Clone)]
+pub struct UintField<const LIMBS: usize> {
+    prime: Uint<LIMBS>,
+    prime_inverted: Uint<LIMBS>,
+    r_squared: Uint<LIMBS>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct UintElement<'a, const LIMBS: usize> {
+    element: Uint<LIMBS>,
+    field: &'a UintField<LIMBS>,
+}
+
+impl<'a, const LIMBS: usize> UintElement<'a, LIMBS> {
+    pub fn new(element: Uint<LIMBS>, field: &'a UintField<LIMBS>) -> Self {
+        Self { element, field }
+    }
+}
+
+/// Using hensel lifting to calculate `prime_inverse` for `prime_inverse` * prime = -1 mod R,
+/// bit by bit like `p_calc_prime_inverse`
+fn uint_calc_prime_inverse<const LIMBS: usize>(prime: &Uint<LIMBS>) -> Uint<LIMBS> {
+    assert!(
+        prime.bit_vartime(0),
+        "Prime {} needs to be coprime to base 2**x, but is not (cannot be divisible by 2)",
+        prime
+    );
+    let mut prime_inv_mod = Uint::ONE;
+    let mut mod_mask = Uint::from_u8(0b11);
+    for bit in 1..Uint::<LIMBS>::BITS {
+        let prime_mod = prime.bitand(&mod_mask);
+        if prime_mod.wrapping_mul(&prime_inv_mod).bitand(&mod_mask) != mod_mask {
+            prime_inv_mod = prime_inv_mod.bitor(&Uint::ONE.shl_vartime(bit));
+        }
+        mod_mask = mod_mask.shl_vartime(1).bitor(&Uint::ONE);
+    }
+    prime_inv_mod
+}
+
+fn uint_calc_r_squared<const LIMBS: usize>(prime: &Uint<LIMBS>) -> Uint<LIMBS> {
+    let r_mod = Uint::MAX
+        .const_rem(prime)
+        .0
+        .wrapping_add(&Uint::ONE)
+        .const_rem(prime)
+        .0;
+    // (R mod p) * R, split into lower and higher half
+    Uint::const_rem_wide((Uint::ZERO, r_mod), prime).0
+}
+
+impl<const LIMBS: usize> UintField<LIMBS> {
+    // Convert to montgomery representation, and use a wrapper to do arithmetic without needing to do manual redc operations
+    pub fn wrap_element(&self, element: Uint<LIMBS>) -> UintElement<'_, LIMBS> {
+        UintElement::new(element.to_montgomery(self), self)
+    }
+    pub fn raw_element(&self, element: Uint<LIMBS>) -> UintElement<'_, LIMBS> {
+        UintElement::new(element, self)
+    }
+}
+
+impl<const LIMBS: usize> UintField<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn canonical_element(&self, value: Uint<LIMBS>) -> Option<UintElement<'_, LIMBS>> {
+        if value < self.prime {
+            Some(self.raw_element(value.to_montgomery_unchecked(self)))
+        } else {
+            None
+        }
+    }
+
+    /// Decode a little endian normal representative, `None` if it is not below the prime
+    pub fn from_bytes_le(
+        &self,
+        bytes: <Uint<LIMBS> as Encoding>::Repr,
+    ) -> Option<UintElement<'_, LIMBS>> {
+        self.canonical_element(Uint::from_le_bytes(bytes))
+    }
+
+    /// Decode a big endian normal representative, `None` if it is not below the prime
+    pub fn from_bytes_be(
+        &self,
+        bytes: <Uint<LIMBS> as Encoding>::Repr,
+    ) -> Option<UintElement<'_, LIMBS>> {
+        self.canonical_element(Uint::from_be_bytes(bytes))
+    }
+}
+
+impl<const LIMBS: usize> UintField<LIMBS> {
+    /// Reduce a little endian double width value modulo the prime
+    ///
+    /// # Panics
+    /// If `bytes` is not exactly twice as long as a `Uint<LIMBS>`
+    pub fn from_uniform_bytes(&self, bytes: &[u8]) -> UintElement<'_, LIMBS> {
+        let half = LIMBS * Limb::BYTES;
+        assert_eq!(
+            bytes.len(),
+            2 * half,
+            "Uniform bytes need to be twice as long as the modulus type"
+        );
+        let lower = Uint::from_le_slice(&bytes[..half]);
+        let higher = Uint::from_le_slice(&bytes[half..]);
+        let value = Uint::const_rem_wide((lower, higher), &self.prime).0;
+        self.raw_element(value.to_montgomery_unchecked(self))
+    }
+}
+
+impl<'a, const LIMBS: usize> UintElement<'a, LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    /// Little endian encoding of the normal representative
+    pub fn to_bytes_le(&self) -> <Uint<LIMBS> as Encoding>::Repr {
+        self.to_normal().to_le_bytes()
+    }
+
+    /// Big endian encoding of the normal representative
+    pub fn to_bytes_be(&self) -> <Uint<LIMBS> as Encoding>::Repr {
+        self.to_normal().to_be_bytes()
+    }
+}
+
+impl<const LIMBS: usize> Field<Uint<LIMBS>> for UintField<LIMBS> {
+    /// Reduce a double width value, given as (lower, higher) half
+    fn redc(&self, value: <Uint<LIMBS> as Redc>::SourceType) -> Uint<LIMBS> {
+        let (lower, higher) = value;
+        let m = lower.wrapping_mul(&self.prime_inverted);
+        let (m_times_prime_lower, m_times_prime_higher) = m.mul_wide(&self.prime);
+        // The lower half is zero by construction, only its carry is relevant
+        let (_, carry) = m_times_prime_lower.adc(&lower, Limb::ZERO);
+        let (tw, carry) = m_times_prime_higher.adc(&higher, carry);
+
+        // Subtract the prime, and add it back when that underflowed without a previous carry
+        let (tw, borrow) = tw.sbb(&self.prime, Limb::ZERO);
+        let (_, borrow) = carry.sbb(Limb::ZERO, borrow);
+        tw.wrapping_add(&self.prime.bitand(&Uint::from_words([borrow.0; LIMBS])))
+    }
+}
+
+impl<const LIMBS: usize> Redc for Uint<LIMBS> {
+    /// Double width value as (lower, higher) half, like returned by `Uint::mul_wide`
+    type SourceType = (Self, Self);
+    type FieldType = UintField<LIMBS>;
+
+    fn setup_field(self) -> Self::FieldType {
+        Self::FieldType {
+            prime: self,
+            prime_inverted: uint_calc_prime_inverse(&self),
+            r_squared: uint_calc_r_squared(&self),
+        }
+    }
+
+    fn to_montgomery_unchecked(self, field: &Self::FieldType) -> Self {
+        debug_assert!(self < field.prime);
+        field.redc(self.mul_wide(&field.r_squared))
+    }
+
+    fn to_montgomery(self, field: &Self::FieldType) -> Self {
+        self.const_rem(&field.prime)
+            .0
+            .to_montgomery_unchecked(field)
+    }
+
+    fn to_normal(self, field: &Self::FieldType) -> Self {
+        field.redc((self, Self::ZERO))
+    }
+
+    /// Square and always multiply over every bit of the exponent, keeping the timing independent of it
+    fn mod_pow(self, exponent: Self, field: &Self::FieldType) -> Self {
+        let mut result = Self::ONE.to_montgomery_unchecked(field);
+        for bit in (0..Self::BITS).rev() {
+            result = field.redc(result.square_wide());
+            let product = field.redc(result.mul_wide(&self));
+            result = Self::conditional_select(&result, &product, Choice::from(exponent.bit(bit)));
+        }
+        result
+    }
+}
+
+impl<'a, const LIMBS: usize> Element for UintElement<'a, LIMBS> {
+    type UnderlyingType = Uint<LIMBS>;
+
+    fn invert(mut self) -> Self {
+        self.element = self
+            .element
+            .mod_pow(self.field.prime.wrapping_sub(&Uint::from_u8(2)), self.field);
+        self
+    }
+
+    fn pow(mut self, exponent: Self) -> Self {
+        self.element = self.element.mod_pow(exponent.element, self.field);
+        self
+    }
+
+    fn internal(&self) -> &Self::UnderlyingType {
+        &self.element
+    }
+
+    fn to_normal(self) -> Self::UnderlyingType {
+        self.element.to_normal(self.field)
+    }
+}
+
+impl<'a, const LIMBS: usize> core::ops::Add for UintElement<'a, LIMBS> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.element = self.element.add_mod(&rhs.element, &self.field.prime);
+        self
+    }
+}
+
+impl<'a, const LIMBS: usize> core::ops::Sub for UintElement<'a, LIMBS> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self.element = self.element.sub_mod(&rhs.element, &self.field.prime);
+        self
+    }
+}
+
+impl<'a, const LIMBS: usize> core::ops::Mul for UintElement<'a, LIMBS> {
+    type Output = Self;
+
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        self.element = self.field.redc(self.element.mul_wide(&rhs.element));
+        self
+    }
+}
@@ -1,3 +1,4 @@
+#[cfg(feature = "u128")]
 use twoword::TwoWord;
 
 use redc::Field;
@@ -45,6 +46,7 @@ fn test_redc_overflow_better() {
     assert_eq!(r.to_normal(&field), 4480);
 }
 
+#[cfg(feature = "u128")]
 #[test]
 fn test_redc_u128() {
     let field = 23u128.setup_field();
@@ -56,6 +58,7 @@ fn test_redc_u128() {
     assert_eq!(r.to_normal(&field), 4);
 }
 
+#[cfg(feature = "u128")]
 #[test]
 fn test_redc_overflow_u128() {
     let field = u128::MAX.setup_field();
@@ -71,6 +74,7 @@ fn test_redc_overflow_u128() {
     assert_eq!(r.to_normal(&field), 4756);
 }
 
+#[cfg(feature = "u128")]
 #[test]
 fn test_redc_overflow_better_u128() {
     let field = (u128::MAX - 2).setup_field();
@@ -86,6 +90,7 @@ fn test_redc_overflow_better_u128() {
     assert_eq!(r.to_normal(&field), 26520);
 }
 
+#[cfg(feature = "rug")]
 #[test]
 fn test_redc_rug() {
     let field = rug::Integer::from(23).setup_field();
@@ -98,3 +103,59 @@ fn test_redc_rug() {
     let r = field.redc(a * b);
     assert_eq!(r.to_normal(&field), 4);
 }
+
+#[test]
+fn test_field_accessors() {
+    use redc::element::Element;
+    use std::collections::HashSet;
+
+    let field = 23u64.setup_field();
+    assert_eq!(field.modulus(), 23);
+    assert_eq!(field.bit_length(), 5);
+    assert_eq!(field.r_bits(), 64);
+    // 2**64 mod 23
+    assert_eq!(field.r_mod_prime(), 6);
+    assert_eq!(field.montgomery_one(), *field.one().internal());
+    assert_eq!(field.r_squared(), 6 * 6 % 23);
+    assert_eq!(field.prime_inverted().wrapping_mul(23), u64::MAX);
+
+    let fields: HashSet<_> = [23u64, 29, 23].iter().map(|p| p.setup_field()).collect();
+    assert_eq!(fields.len(), 2);
+    assert!(fields.contains(&23u64.setup_field()));
+    assert_ne!(23u64.setup_field(), 29u64.setup_field());
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_field_accessors_u128() {
+    let field = (u128::MAX - 158).setup_field();
+    assert_eq!(field.modulus(), u128::MAX - 158);
+    assert_eq!(field.bit_length(), 128);
+    assert_eq!(field.r_bits(), 128);
+    assert_eq!(field.r_mod_prime(), 159);
+    assert_eq!(field.r_squared(), 159 * 159);
+    assert_eq!(
+        field.prime_inverted().wrapping_mul(u128::MAX - 158),
+        u128::MAX
+    );
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_field_accessors_rug() {
+    use redc::element::Element;
+    let field = rug::Integer::from(23).setup_field();
+    assert_eq!(*field.modulus(), 23);
+    assert_eq!(field.bit_length(), 5);
+    assert_eq!(field.r_bits(), 5);
+    assert_eq!(field.r(), 32);
+    assert_eq!(field.r_mod_prime(), 9);
+    assert_eq!(field.montgomery_one(), *field.one().internal());
+    assert_eq!(*field.r_squared(), 81 % 23);
+    assert_eq!(
+        (field.prime_inverted().clone() * 23u32 + 1u32).keep_bits(5),
+        0
+    );
+    assert_eq!(field, rug::Integer::from(23).setup_field());
+    assert_ne!(field, rug::Integer::from(29).setup_field());
+}
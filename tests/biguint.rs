@@ -0,0 +1,139 @@
+#![cfg(feature = "num-bigint")]
+use num_bigint::BigUint;
+use redc::element::Element;
+use redc::{Field, Redc};
+
+#[test]
+fn test_redc_biguint() {
+    let field = BigUint::from(23u32).setup_field();
+    let a = BigUint::from(7u32).to_montgomery(&field);
+    assert_eq!(a, BigUint::from(17u32));
+    let b = BigUint::from(17u32).to_montgomery(&field);
+    assert_eq!(b, BigUint::from(15u32));
+    let r = field.redc(a * b);
+    assert_eq!(r.to_normal(&field), BigUint::from(4u32));
+}
+
+#[test]
+fn test_biguint_invert() {
+    // 2**255 - 19
+    let prime = (BigUint::from(1u32) << 255u32) - 19u32;
+    let field = prime.clone().setup_field();
+    for value in [2u32, 3, 12345, u32::MAX] {
+        let x = field.wrap_element(BigUint::from(value));
+        assert_eq!((x.clone() * x.invert()).to_normal(), BigUint::from(1u32));
+    }
+    let x = field.wrap_element(prime - 1u32);
+    assert_eq!((x.clone() * x).to_normal(), BigUint::from(1u32));
+}
+
+#[cfg(feature = "rug")]
+mod differential {
+    use super::*;
+
+    fn to_biguint(value: &rug::Integer) -> BigUint {
+        BigUint::parse_bytes(format!("{:x}", value).as_bytes(), 16).unwrap()
+    }
+
+    fn moduli() -> Vec<rug::Integer> {
+        vec![
+            rug::Integer::from(23),
+            rug::Integer::from(u64::MAX - 58),
+            (rug::Integer::from(1) << 127) - 1,
+            (rug::Integer::from(1) << 255) - 19,
+            (rug::Integer::from(1) << 521) - 1,
+        ]
+    }
+
+    #[test]
+    fn test_field_setup_matches_rug() {
+        for modulus in moduli() {
+            let rug_field = modulus.clone().setup_field();
+            let big_field = to_biguint(&modulus).setup_field();
+            for value in [0u64, 1, 2, 12345, u64::MAX] {
+                let rug_mont = rug::Integer::from(value).to_montgomery(&rug_field);
+                let big_mont = BigUint::from(value).to_montgomery(&big_field);
+                assert_eq!(to_biguint(&rug_mont), big_mont);
+                assert_eq!(
+                    to_biguint(&rug_mont.to_normal(&rug_field)),
+                    big_mont.to_normal(&big_field)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_arithmetic_matches_rug() {
+        for modulus in moduli() {
+            let rug_field = modulus.clone().setup_field();
+            let big_field = to_biguint(&modulus).setup_field();
+            let start = rug::Integer::from(0xdead_beef_u64) * 0x1234_5678_u64;
+            let step = rug::Integer::from(&modulus - 3);
+            let mut r = rug_field.wrap_element(start.clone());
+            let mut b = big_field.wrap_element(to_biguint(&start));
+            let r_step = rug_field.wrap_element(step.clone());
+            let b_step = big_field.wrap_element(to_biguint(&step));
+            for i in 0..200 {
+                r = r.clone() * r + r_step.clone();
+                b = b.clone() * b + b_step.clone();
+                if i % 3 == 0 {
+                    r = r - r_step.clone() - r_step.clone();
+                    b = b - b_step.clone() - b_step.clone();
+                }
+                assert_eq!(to_biguint(&r.clone().to_normal()), b.clone().to_normal());
+            }
+        }
+    }
+
+    #[test]
+    fn test_mod_pow_matches_rug() {
+        for modulus in moduli() {
+            let rug_field = modulus.clone().setup_field();
+            let big_field = to_biguint(&modulus).setup_field();
+            let base = rug::Integer::from(0xfeed_u64 << 40);
+            let exponent = rug::Integer::from(&modulus - 7);
+            let r = base
+                .clone()
+                .to_montgomery(&rug_field)
+                .mod_pow(exponent.clone(), &rug_field)
+                .to_normal(&rug_field);
+            let b = to_biguint(&base)
+                .to_montgomery(&big_field)
+                .mod_pow(to_biguint(&exponent), &big_field)
+                .to_normal(&big_field);
+            assert_eq!(to_biguint(&r), b);
+            let expected = base.pow_mod(&exponent, &modulus).unwrap();
+            assert_eq!(r, expected);
+        }
+    }
+}
+
+#[test]
+fn test_biguint_bytes() {
+    let prime: BigUint = (BigUint::from(1u32) << 127u32) - 1u32;
+    let field = prime.clone().setup_field();
+    assert_eq!(field.byte_len(), 16);
+    let x = field.wrap_element(BigUint::from(0x0102_0304u32));
+    let mut expected = [0u8; 16];
+    expected[..4].copy_from_slice(&[4, 3, 2, 1]);
+    assert_eq!(x.to_bytes_le(), expected);
+    expected.reverse();
+    assert_eq!(x.to_bytes_be(), expected);
+    assert_eq!(
+        field.from_bytes_be(&expected).unwrap().to_normal(),
+        BigUint::from(0x0102_0304u32)
+    );
+    assert!(field.from_bytes_le(&[0xff; 16]).is_none());
+    assert!(field.from_bytes_le(&[0; 17]).is_none());
+    assert_eq!(
+        field.from_uniform_bytes(&[0xff; 32]).to_normal(),
+        ((BigUint::from(1u32) << 256u32) - 1u32) % prime
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_biguint_uniform_bytes_length() {
+    let field = ((BigUint::from(1u32) << 127u32) - 1u32).setup_field();
+    field.from_uniform_bytes(&[0xff; 16]);
+}
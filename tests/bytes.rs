@@ -0,0 +1,130 @@
+mod common;
+
+use common::Goldilocks;
+use redc::constant::{ConstPrimeElement, ConstPrimeModulus};
+use redc::element::Element;
+use redc::Redc;
+
+#[test]
+fn test_bytes_u64() {
+    let prime = 18_446_744_073_709_551_557u64;
+    let field = prime.setup_field();
+    let x = field.wrap_element(0x0102_0304_0506_0708);
+    assert_eq!(x.to_bytes_le(), [8, 7, 6, 5, 4, 3, 2, 1]);
+    assert_eq!(x.to_bytes_be(), [1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(
+        field.from_bytes_le(&x.to_bytes_le()).unwrap().to_normal(),
+        x.to_normal()
+    );
+    assert_eq!(
+        field.from_bytes_be(&x.to_bytes_be()).unwrap().to_normal(),
+        x.to_normal()
+    );
+    assert!(field.from_bytes_le(&prime.to_le_bytes()).is_none());
+    assert!(field.from_bytes_be(&u64::MAX.to_be_bytes()).is_none());
+    assert!(field.from_bytes_le(&(prime - 1).to_le_bytes()).is_some());
+
+    let wide = 0x0123_4567_89ab_cdef_fedc_ba98_7654_3210u128;
+    assert_eq!(
+        u128::from(field.from_uniform_bytes(&wide.to_le_bytes()).to_normal()),
+        wide % u128::from(prime)
+    );
+    assert_eq!(
+        field.from_uniform_bytes(&[0xff; 16]).to_normal(),
+        (u128::MAX % u128::from(prime)) as u64
+    );
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_bytes_u128() {
+    let prime = 340_282_366_920_938_463_463_374_607_431_768_211_297u128;
+    let field = prime.setup_field();
+    let x = field.wrap_element(prime - 2);
+    assert_eq!(x.to_bytes_le(), (prime - 2).to_le_bytes());
+    assert_eq!(
+        field.from_bytes_be(&x.to_bytes_be()).unwrap().to_normal(),
+        prime - 2
+    );
+    assert!(field.from_bytes_le(&prime.to_le_bytes()).is_none());
+
+    // 2**128 = 159 mod prime, so lower + higher * 2**128 = lower + 159 * higher
+    let mut wide = [0u8; 32];
+    wide[..16].copy_from_slice(&5u128.to_le_bytes());
+    wide[16..].copy_from_slice(&3u128.to_le_bytes());
+    assert_eq!(field.from_uniform_bytes(&wide).to_normal(), 5 + 3 * 159);
+    assert_eq!(
+        field.from_uniform_bytes(&[0xff; 32]).to_normal(),
+        (u128::MAX % prime) + (u128::MAX % prime) * 159 % prime
+    );
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_bytes_rug() {
+    let prime = (rug::Integer::from(1) << 127u32) - 1u32;
+    let field = prime.clone().setup_field();
+    assert_eq!(field.byte_len(), 16);
+    let value = rug::Integer::from(0x0102_0304u32);
+    let x = field.wrap_element(value.clone());
+    let mut expected = [0u8; 16];
+    expected[..4].copy_from_slice(&[4, 3, 2, 1]);
+    assert_eq!(x.to_bytes_le(), expected);
+    expected.reverse();
+    assert_eq!(x.to_bytes_be(), expected);
+    assert_eq!(field.from_bytes_be(&expected).unwrap().to_normal(), value);
+    assert!(field.from_bytes_le(&expected[1..]).is_none());
+    assert!(field.from_bytes_le(&[0xff; 16]).is_none());
+
+    let wide = [0xffu8; 32];
+    let expected = (rug::Integer::from(1) << 256u32) - 1u32;
+    assert_eq!(
+        field.from_uniform_bytes(&wide).to_normal(),
+        expected % prime
+    );
+}
+
+#[cfg(feature = "rug")]
+#[test]
+#[should_panic]
+fn test_bytes_rug_uniform_length() {
+    let field = ((rug::Integer::from(1) << 127u32) - 1u32).setup_field();
+    field.from_uniform_bytes(&[0xff; 31]);
+}
+
+#[test]
+fn test_bytes_signed() {
+    let field = 3329i32.setup_field();
+    let x = field.wrap_element(-1);
+    assert_eq!(x.to_bytes_le(), 3328u32.to_le_bytes());
+    assert_eq!(x.to_bytes_be(), 3328u32.to_be_bytes());
+    assert_eq!(
+        field.from_bytes_le(&x.to_bytes_le()).unwrap().to_centered(),
+        -1
+    );
+    assert!(field.from_bytes_le(&3329u32.to_le_bytes()).is_none());
+    assert_eq!(
+        field
+            .from_uniform_bytes(&u64::MAX.to_le_bytes())
+            .to_centered(),
+        field.to_centered((u64::MAX % 3329) as i32)
+    );
+
+    let field = 0x3fff_ffff_ffff_ffc5i64.setup_field();
+    let x = field.from_bytes_be(&12345u64.to_be_bytes()).unwrap();
+    assert_eq!(x.to_normal(), 12345);
+    assert_eq!(x.to_bytes_le(), 12345u64.to_le_bytes());
+}
+
+#[test]
+fn test_bytes_const() {
+    type F = ConstPrimeElement<Goldilocks>;
+    let x = F::new(42);
+    assert_eq!(F::from_bytes_le(&x.to_bytes_le()), Some(x));
+    assert_eq!(F::from_bytes_be(&x.to_bytes_be()), Some(x));
+    assert_eq!(F::from_bytes_le(&Goldilocks::MODULUS.to_le_bytes()), None);
+    // 2**64 = 2**32 - 1 mod the Goldilocks prime
+    let mut wide = [0u8; 16];
+    wide[8] = 1;
+    assert_eq!(F::from_uniform_bytes(&wide), F::new(0xffff_ffff));
+}
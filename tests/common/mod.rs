@@ -0,0 +1,11 @@
+//! Fixtures shared by the integration tests
+use redc::constant::ConstPrimeModulus;
+
+/// The prime 2**64 - 2**32 + 1
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Goldilocks;
+
+impl ConstPrimeModulus for Goldilocks {
+    const MODULUS: u64 = 0xffff_ffff_0000_0001;
+    const GENERATOR: u64 = 7;
+}
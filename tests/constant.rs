@@ -0,0 +1,139 @@
+mod common;
+
+use common::Goldilocks;
+use redc::constant::{ConstPrimeElement, ConstPrimeModulus};
+use redc::element::Element;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct Small;
+
+impl ConstPrimeModulus for Small {
+    const MODULUS: u64 = 97;
+    const GENERATOR: u64 = 5;
+}
+
+type Fg = ConstPrimeElement<Goldilocks>;
+type Fs = ConstPrimeElement<Small>;
+
+#[test]
+fn test_const_arithmetic() {
+    let modulus = u128::from(Goldilocks::MODULUS);
+    let mut x = 12345u128;
+    let mut n = Fg::new(12345);
+    let increment = Fg::new(u64::MAX);
+    for _ in 0..1000 {
+        x = (x * x + u128::from(u64::MAX)) % modulus;
+        n = n * n + increment;
+        assert_eq!(u128::from(n.to_u64()), x);
+    }
+    assert_eq!((-Fg::new(1)).to_u64(), Goldilocks::MODULUS - 1);
+    assert_eq!((Fg::new(3) - Fg::new(5)).to_u64(), Goldilocks::MODULUS - 2);
+    assert_eq!((Fg::new(3).invert() * Fg::new(3)).to_u64(), 1);
+    let values = [Fg::new(2), Fg::new(3), Fg::new(7)];
+    assert_eq!(values.iter().sum::<Fg>().to_u64(), 12);
+    assert_eq!(values.iter().product::<Fg>().to_u64(), 42);
+}
+
+#[test]
+fn test_runtime_field_agrees() {
+    use redc::Redc;
+    let field = Goldilocks::MODULUS.setup_field();
+    for value in [0, 1, 2, u64::MAX, Goldilocks::MODULUS - 1] {
+        assert_eq!(*Fs::new(value).internal(), value.to_montgomery(&Fs::FIELD));
+        assert_eq!(
+            *Fg::new(value).internal(),
+            *field.wrap_element(value).internal()
+        );
+    }
+}
+
+#[cfg(feature = "ff")]
+mod prime_field {
+    use super::*;
+    use ff::{Field, PrimeField};
+
+    struct XorShift(u64);
+
+    impl rand_core::RngCore for XorShift {
+        fn next_u32(&mut self) -> u32 {
+            (self.next_u64() >> 32) as u32
+        }
+        fn next_u64(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::impls::fill_bytes_via_next(self, dest)
+        }
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    fn check_constants<F: PrimeField>() {
+        assert_eq!(F::ONE.double() * F::TWO_INV, F::ONE);
+        assert_eq!(F::ROOT_OF_UNITY * F::ROOT_OF_UNITY_INV, F::ONE);
+        let mut root = F::ROOT_OF_UNITY;
+        for _ in 0..F::S - 1 {
+            root = root.square();
+        }
+        assert_eq!(root, -F::ONE);
+        assert_eq!(root.square(), F::ONE);
+        assert!(bool::from(F::MULTIPLICATIVE_GENERATOR.sqrt().is_none()));
+        let mut delta = F::MULTIPLICATIVE_GENERATOR;
+        for _ in 0..F::S {
+            delta = delta.square();
+        }
+        assert_eq!(delta, F::DELTA);
+    }
+
+    #[test]
+    fn test_ff_constants() {
+        check_constants::<Fg>();
+        check_constants::<Fs>();
+        assert_eq!(Fg::MODULUS, "0xffffffff00000001");
+        assert_eq!(Fs::MODULUS, "0x0000000000000061");
+        assert_eq!(Fg::NUM_BITS, 64);
+        assert_eq!(Fs::NUM_BITS, 7);
+        assert_eq!(Fg::S, 32);
+        assert_eq!(Fs::S, 5);
+        assert_eq!(Fg::ZERO, Fg::default());
+        assert_eq!(Fs::ONE.to_u64(), 1);
+    }
+
+    #[test]
+    fn test_ff_sqrt_invert() {
+        for value in 0..97 {
+            let x = Fs::new(value);
+            let square = x.square();
+            let root = square.sqrt().unwrap();
+            assert!(root == x || root == -x);
+            if value != 0 {
+                assert_eq!(Field::invert(&x).unwrap() * x, Fs::ONE);
+            }
+        }
+        assert!(bool::from(Field::invert(&Fs::ZERO).is_none()));
+        let mut rng = XorShift(0x1234_5678_9abc_def0);
+        for _ in 0..100 {
+            let x = Fg::random(&mut rng);
+            assert_eq!(x.square().sqrt().unwrap().square(), x.square());
+        }
+    }
+
+    #[test]
+    fn test_ff_repr() {
+        let mut rng = XorShift(42);
+        for _ in 0..100 {
+            let x = Fg::random(&mut rng);
+            assert_eq!(Fg::from_repr(x.to_repr()).unwrap(), x);
+            assert_eq!(bool::from(x.is_odd()), x.to_u64() % 2 == 1);
+        }
+        assert!(bool::from(
+            Fg::from_repr(Goldilocks::MODULUS.to_le_bytes()).is_none()
+        ));
+        assert_eq!(Fg::from_repr(5u64.to_le_bytes()).unwrap(), Fg::from(5));
+    }
+}
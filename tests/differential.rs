@@ -0,0 +1,151 @@
+#![cfg(all(feature = "rug", any(feature = "proptest", feature = "quickcheck")))]
+//! Differential tests of the primitive integer fields against rug
+use redc::element::Element;
+use redc::Redc;
+use rug::Integer;
+
+#[cfg(feature = "proptest")]
+mod strategies {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+    use redc::arbitrary::proptest::{element_u64, odd_modulus_u64, prime_modulus_u64};
+    use redc::element::FieldOps;
+
+    fn reference_pow(base: &Integer, exponent: u64, modulus: &Integer) -> Integer {
+        Integer::from(base.pow_mod_ref(&Integer::from(exponent), modulus).unwrap())
+    }
+
+    fn modulus_with_elements(
+        modulus: impl Strategy<Value = u64>,
+    ) -> impl Strategy<Value = (u64, Vec<u64>)> {
+        modulus.prop_flat_map(|m| (Just(m), vec(element_u64(m), 3)))
+    }
+
+    proptest! {
+        #[test]
+        fn arithmetic_u64((modulus, values) in modulus_with_elements(odd_modulus_u64()), exponent: u64) {
+            let field = modulus.setup_field();
+            let [a, b, c] = [values[0], values[1], values[2]].map(|v| field.wrap_element(v));
+            let [ra, rb, rc] = [values[0], values[1], values[2]].map(Integer::from);
+            let m = Integer::from(modulus);
+
+            prop_assert_eq!(a.to_normal(), values[0]);
+            prop_assert_eq!(Integer::from((a + b).to_normal()), Integer::from(&ra + &rb) % &m);
+            prop_assert_eq!(
+                Integer::from((a - b).to_normal()),
+                (Integer::from(&ra - &rb) % &m + &m) % &m
+            );
+            prop_assert_eq!(Integer::from((a * b * c).to_normal()), ra.clone() * &rb * &rc % &m);
+            prop_assert_eq!(Integer::from((-a).to_normal()), (Integer::from(&m - &ra)) % &m);
+            prop_assert_eq!(Integer::from(a.pow_u64(exponent).to_normal()), reference_pow(&ra, exponent, &m));
+        }
+
+        #[test]
+        fn invert_u64((modulus, values) in modulus_with_elements(prime_modulus_u64())) {
+            let field = modulus.setup_field();
+            let a = field.wrap_element(values[0]);
+            prop_assume!(!a.is_zero());
+            let expected = Integer::from(values[0]).invert(&Integer::from(modulus)).unwrap();
+            prop_assert_eq!(Integer::from(a.invert().to_normal()), expected);
+        }
+    }
+
+    #[cfg(feature = "u128")]
+    mod u128 {
+        use super::*;
+        use redc::arbitrary::proptest::{element_u128, odd_modulus_u128, prime_modulus_u128};
+
+        fn modulus_with_elements(
+            modulus: impl Strategy<Value = u128>,
+        ) -> impl Strategy<Value = (u128, Vec<u128>)> {
+            modulus.prop_flat_map(|m| (Just(m), vec(element_u128(m), 3)))
+        }
+
+        proptest! {
+            #[test]
+            fn arithmetic_u128((modulus, values) in modulus_with_elements(odd_modulus_u128()), exponent: u64) {
+                let field = modulus.setup_field();
+                let [a, b, c] = [values[0], values[1], values[2]].map(|v| field.wrap_element(v));
+                let [ra, rb, rc] = [values[0], values[1], values[2]].map(Integer::from);
+                let m = Integer::from(modulus);
+
+                prop_assert_eq!(a.to_normal(), values[0]);
+                prop_assert_eq!(Integer::from((a + b).to_normal()), Integer::from(&ra + &rb) % &m);
+                prop_assert_eq!(
+                    Integer::from((a - b).to_normal()),
+                    (Integer::from(&ra - &rb) % &m + &m) % &m
+                );
+                prop_assert_eq!(Integer::from((a * b * c).to_normal()), ra.clone() * &rb * &rc % &m);
+                prop_assert_eq!(Integer::from(a.pow_u64(exponent).to_normal()), reference_pow(&ra, exponent, &m));
+            }
+
+            #[test]
+            fn invert_u128((modulus, values) in modulus_with_elements(prime_modulus_u128())) {
+                let field = modulus.setup_field();
+                let a = field.wrap_element(values[0]);
+                prop_assume!(!a.is_zero());
+                let expected = Integer::from(values[0]).invert(&Integer::from(modulus)).unwrap();
+                prop_assert_eq!(Integer::from(a.invert().to_normal()), expected);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+mod properties {
+    use super::*;
+    use quickcheck::quickcheck;
+    use redc::arbitrary::quickcheck::{FieldElements, OddModulus, PrimeModulus};
+
+    fn mul_matches(sample: FieldElements<OddModulus<u64>, u64>) -> bool {
+        let field = sample.modulus.0.setup_field();
+        let m = Integer::from(sample.modulus.0);
+        let product = sample
+            .elements
+            .iter()
+            .fold(field.one(), |acc, &v| acc * field.wrap_element(v));
+        let expected = sample
+            .elements
+            .iter()
+            .fold(Integer::from(1) % &m, |acc, &v| acc * v % &m);
+        expected == product.to_normal()
+    }
+
+    fn inverse_matches(sample: FieldElements<PrimeModulus<u64>, u64>) -> bool {
+        let field = sample.modulus.0.setup_field();
+        sample
+            .elements
+            .iter()
+            .map(|&v| field.wrap_element(v))
+            .filter(|x| !x.is_zero())
+            .all(|x| (x * x.invert()).is_one())
+    }
+
+    #[test]
+    fn quickcheck_u64() {
+        quickcheck(mul_matches as fn(FieldElements<OddModulus<u64>, u64>) -> bool);
+        quickcheck(inverse_matches as fn(FieldElements<PrimeModulus<u64>, u64>) -> bool);
+    }
+
+    #[cfg(feature = "u128")]
+    fn sum_matches_u128(sample: FieldElements<OddModulus<u128>, u128>) -> bool {
+        let field = sample.modulus.0.setup_field();
+        let m = Integer::from(sample.modulus.0);
+        let sum = sample
+            .elements
+            .iter()
+            .fold(field.zero(), |acc, &v| acc + field.wrap_element(v));
+        let expected = sample
+            .elements
+            .iter()
+            .fold(Integer::new(), |acc, &v| (acc + v) % &m);
+        expected == sum.to_normal()
+    }
+
+    #[cfg(feature = "u128")]
+    #[test]
+    fn quickcheck_u128() {
+        quickcheck(sum_matches_u128 as fn(FieldElements<OddModulus<u128>, u128>) -> bool);
+    }
+}
@@ -24,3 +24,89 @@ fn test_u64() {
         }
     }
 }
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_rug_add_sub() {
+    let prime = rug::Integer::from(u64::MAX - 58);
+    let field = prime.clone().setup_field();
+    let minus_one = field.wrap_element(prime.clone() - 1u32);
+    let zero = field.wrap_element(rug::Integer::new());
+    let one = field.wrap_element(rug::Integer::from(1));
+    assert_eq!(
+        (minus_one.clone() + minus_one.clone()).to_normal(),
+        prime.clone() - 2u32
+    );
+    assert_eq!((minus_one.clone() + one.clone()).to_normal(), 0);
+    assert_eq!((zero - one.clone()).to_normal(), prime.clone() - 1u32);
+    assert_eq!((one.clone() - one).to_normal(), 0);
+    assert_eq!((minus_one.clone() - minus_one).to_normal(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_batch_invert() {
+    let field = (u64::MAX - 58).setup_field();
+    let original: Vec<_> = (1..100u64).map(|x| field.wrap_element(x << 40)).collect();
+    let mut inverted = original.clone();
+    redc::batch::batch_invert(&mut inverted);
+    for (x, x_inv) in original.into_iter().zip(inverted) {
+        assert_eq!((x * x_inv).to_normal(), 1);
+    }
+    redc::batch::batch_invert::<redc::element::PrimIntElement<u64>>(&mut []);
+}
+
+#[test]
+fn test_checked_construction() {
+    use redc::error::OutOfRangeError;
+
+    let modulus = u64::MAX - 58;
+    let field = modulus.setup_field();
+    assert_eq!(field.try_raw_element(modulus).unwrap_err(), OutOfRangeError);
+    assert_eq!(
+        field.try_raw_element(u64::MAX).unwrap_err(),
+        OutOfRangeError
+    );
+    let raw = field.try_raw_element(modulus - 1).unwrap();
+    assert_eq!(*raw.internal(), modulus - 1);
+
+    assert_eq!(
+        field.try_from_canonical(modulus).unwrap_err(),
+        OutOfRangeError
+    );
+    let x = field.try_from_canonical(modulus - 1).unwrap();
+    assert_eq!(x.to_normal(), modulus - 1);
+    assert_eq!((x + field.wrap_element(2)).to_normal(), 1);
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_checked_construction_u128() {
+    let modulus = u128::MAX - 158;
+    let field = modulus.setup_field();
+    assert!(field.try_raw_element(modulus).is_err());
+    assert!(field.try_from_canonical(u128::MAX).is_err());
+    assert_eq!(field.try_from_canonical(5).unwrap().to_normal(), 5);
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_checked_construction_rug() {
+    let modulus = rug::Integer::from(1_000_000_007);
+    let field = modulus.clone().setup_field();
+    assert!(field.try_raw_element(modulus.clone()).is_err());
+    assert!(field.try_raw_element(rug::Integer::from(-1)).is_err());
+    assert!(field.try_from_canonical(modulus.clone() + 1u32).is_err());
+    assert!(field.try_from_canonical(rug::Integer::from(-1)).is_err());
+
+    let x = field.try_from_canonical(rug::Integer::from(5)).unwrap();
+    let raw = field.raw_element(x.internal().clone());
+    assert_eq!(raw.to_normal(), 5);
+    assert_eq!(
+        field
+            .try_raw_element(x.internal().clone())
+            .unwrap()
+            .to_normal(),
+        5
+    );
+}
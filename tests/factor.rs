@@ -0,0 +1,305 @@
+#![cfg(feature = "alloc")]
+use redc::factor::factor_u64;
+
+fn trial_division(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    let mut divisor = 2;
+    while divisor * divisor <= n {
+        let mut exponent = 0;
+        while n.is_multiple_of(divisor) {
+            n /= divisor;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((divisor, exponent));
+        }
+        divisor += 1;
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+fn product(factors: &[(u64, u32)]) -> u64 {
+    factors.iter().map(|&(p, e)| p.pow(e)).product()
+}
+
+#[test]
+fn test_factor_small() {
+    assert!(factor_u64(0).is_empty());
+    assert!(factor_u64(1).is_empty());
+    for n in 2..20_000 {
+        assert_eq!(factor_u64(n), trial_division(n), "{}", n);
+    }
+}
+
+#[test]
+fn test_factor_u64() {
+    assert_eq!(
+        factor_u64(u64::MAX),
+        vec![
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (6_700_417, 1)
+        ]
+    );
+    // Semiprimes without small factors, squares and cubes
+    for factors in [
+        vec![(4_294_967_279u64, 1), (4_294_967_291, 1)],
+        vec![(1_000_003, 1), (1_000_000_007, 1)],
+        vec![(4_294_967_291, 2)],
+        vec![(1_009, 1), (2_097_143, 2)],
+        vec![(2, 10), (3, 5), (1_000_000_007, 1)],
+        vec![(u64::MAX - 58, 1)],
+        vec![(3_037_000_453, 1), (3_037_000_493, 1)],
+    ] {
+        assert_eq!(factor_u64(product(&factors)), factors);
+    }
+    // Strong pseudoprimes need the primality test to be exact
+    assert_eq!(
+        factor_u64(3_825_123_056_546_413_051),
+        vec![(149_491, 1), (747_451, 1), (34_233_211, 1)]
+    );
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_factor_u128() {
+    use redc::factor::factor_u128;
+
+    assert_eq!(
+        factor_u128(u128::MAX),
+        vec![
+            (3, 1),
+            (5, 1),
+            (17, 1),
+            (257, 1),
+            (641, 1),
+            (65537, 1),
+            (274_177, 1),
+            (6_700_417, 1),
+            (67_280_421_310_721, 1)
+        ]
+    );
+    for factors in [
+        vec![(1_000_000_007u128, 1), ((1 << 89) - 1, 1)],
+        vec![((1 << 61) - 1, 2)],
+        vec![
+            (2, 3),
+            (1_000_003, 1),
+            (1_000_000_007, 1),
+            (1_099_511_627_791, 1),
+        ],
+        vec![(u128::MAX - 158, 1)],
+        vec![(68_719_476_767, 1), (137_438_953_481, 1)],
+    ] {
+        let n = factors.iter().map(|&(p, e)| p.pow(e)).product();
+        assert_eq!(factor_u128(n), factors, "{}", n);
+    }
+    assert_eq!(factor_u128(97), vec![(97, 1)]);
+}
+
+#[test]
+fn test_pollard_pm1() {
+    use redc::Redc;
+
+    // p - 1 is 1000-smooth, q - 1 and q + 1 have a prime factor above 10**7
+    let (p, q) = (3_577_595_843u64, 2_115_110_807u64);
+    let field = (p * q).setup_field();
+    assert_eq!(field.pollard_pm1(10_000, 0), Some(p));
+    assert_eq!(field.pollard_pm1(100, 1_000), None);
+    // p - 1 = 50021 * (1000-smooth)
+    let (p, q) = (3_230_656_307u64, 1_845_513_941u64);
+    let field = (p * q).setup_field();
+    assert_eq!(field.pollard_pm1(10_000, 0), None);
+    assert_eq!(field.pollard_pm1(10_000, 100_000), Some(p));
+}
+
+#[test]
+fn test_williams_pp1() {
+    use redc::Redc;
+
+    // p + 1 is 1000-smooth
+    let (p, q) = (3_557_500_693u64, 1_923_793_477u64);
+    let field = (p * q).setup_field();
+    assert_eq!(field.williams_pp1(10_000, 0), Some(p));
+    // p + 1 = 50021 * (1000-smooth)
+    let (p, q) = (3_645_230_353u64, 1_447_902_473u64);
+    let field = (p * q).setup_field();
+    assert_eq!(field.williams_pp1(10_000, 0), None);
+    assert_eq!(field.williams_pp1(10_000, 100_000), Some(p));
+    // p + 1 = 2 * 97 * 139 * 293 * 401 and q + 1 = 2 * 79 * 127 * 283 * 601 complete in the same block of primes
+    let (p, q) = (3_168_316_237u64, 3_412_885_477u64);
+    let field = (p * q).setup_field();
+    assert_eq!(field.williams_pp1(1_000, 0), Some(p));
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_pm1_pp1_u128() {
+    use redc::Redc;
+
+    let q = 13_452_039_370_465_238_759u128;
+    let p = 700_833_872_429_555_303u128;
+    assert_eq!((p * q).setup_field().pollard_pm1(10_000, 100_000), Some(p));
+    let q = 14_197_801_028_646_749_719u128;
+    let p = 695_415_133_798_532_197u128;
+    assert_eq!((p * q).setup_field().williams_pp1(10_000, 100_000), Some(p));
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_pm1_pp1_rug() {
+    use redc::Redc;
+    use rug::Integer;
+
+    let rough: Integer = "835162716760317479641062101386163761".parse().unwrap();
+    let p: Integer = "686619685535355700533801261947".parse().unwrap();
+    let field = Integer::from(&p * &rough).setup_field();
+    assert_eq!(field.pollard_pm1(20_000, 200_000), Some(p));
+    let p: Integer = "832767304849057439943262866757".parse().unwrap();
+    let field = Integer::from(&p * &rough).setup_field();
+    assert_eq!(field.williams_pp1(20_000, 200_000), Some(p));
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_ecm_factor_rug() {
+    use redc::factor::ecm::ecm_factor;
+    use rug::Integer;
+
+    let p = Integer::from(300_000_000_012_371u64);
+    let q: Integer = "7000000000000000000000000000000000001003".parse().unwrap();
+    let n = Integer::from(&p * &q);
+    assert_eq!(ecm_factor(&n, 2_000, 200), Some(p));
+    // A 20 digit factor, which the second curve finds
+    let p: Integer = "30000000000000000679".parse().unwrap();
+    let n = Integer::from(&p * &q);
+    assert_eq!(ecm_factor(&n, 11_000, 2), Some(p));
+    assert_eq!(ecm_factor(&q, 500, 3), None);
+    assert_eq!(
+        ecm_factor(&Integer::from(&q * 2u32), 500, 3),
+        Some(Integer::from(2))
+    );
+}
+
+// The 277th curve finds the 30 digit factor, after about 16 minutes in release builds
+#[cfg(feature = "rug")]
+#[test]
+#[ignore]
+fn test_ecm_factor_30_digits() {
+    use redc::factor::ecm::ecm_factor;
+    use rug::Integer;
+
+    let p: Integer = "100000000000000000000123456817".parse().unwrap();
+    let q: Integer = "7000000000000000000000000000000000001003".parse().unwrap();
+    let n = Integer::from(&p * &q);
+    assert_eq!(ecm_factor(&n, 250_000, 300), Some(p));
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_ecm_factor_u128() {
+    use redc::factor::ecm::ecm_factor_u128;
+
+    let p = 1_099_511_640_127u128;
+    let q = 1_208_925_819_614_629_174_707_049u128;
+    assert_eq!(ecm_factor_u128(p * q, 500, 200), Some(p));
+    assert_eq!(ecm_factor_u128(q, 500, 3), None);
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_siqs_factor() {
+    use redc::factor::siqs::siqs_factor;
+    use rug::Integer;
+
+    for (p, q) in [
+        ("428571428571431", "454545454545493"),
+        ("42857142857142857143", "45454545454545454583"),
+    ] {
+        let p: Integer = p.parse().unwrap();
+        let q: Integer = q.parse().unwrap();
+        let n = Integer::from(&p * &q);
+        let factor = siqs_factor(&n).unwrap();
+        assert!(factor == p || factor == q);
+    }
+}
+
+// A second in release builds, but minutes in debug builds, so it runs with `cargo test --release`
+#[cfg(feature = "rug")]
+#[test]
+#[cfg_attr(debug_assertions, ignore)]
+fn test_siqs_factor_50_digits() {
+    use redc::factor::siqs::siqs_factor;
+    use rug::Integer;
+
+    let p: Integer = "4285714285714285714285753".parse().unwrap();
+    let q: Integer = "4545454545454545454545647".parse().unwrap();
+    let n = Integer::from(&p * &q);
+    let factor = siqs_factor(&n).unwrap();
+    assert!(factor == p || factor == q);
+}
+
+// Several minutes even in release builds, so it only runs with `cargo test --release -- --ignored`
+#[cfg(feature = "rug")]
+#[test]
+#[ignore]
+fn test_siqs_factor_80_digits() {
+    use redc::factor::siqs::siqs_factor;
+    use rug::Integer;
+
+    let p: Integer = "4285714285714285714285714285714285714397".parse().unwrap();
+    let q: Integer = "4545454545454545454545454545454545454621".parse().unwrap();
+    let n = Integer::from(&p * &q);
+    let factor = siqs_factor(&n).unwrap();
+    assert!(factor == p || factor == q);
+}
+
+// About an hour in release builds, so it also only runs with `cargo test --release -- --ignored`
+#[cfg(feature = "rug")]
+#[test]
+#[ignore]
+fn test_siqs_factor_90_digits() {
+    use redc::factor::siqs::siqs_factor;
+    use rug::Integer;
+
+    let p: Integer = "428571428571428571428571428571428571428571551"
+        .parse()
+        .unwrap();
+    let q: Integer = "454545454545454545454545454545454545454545491"
+        .parse()
+        .unwrap();
+    let n = Integer::from(&p * &q);
+    let factor = siqs_factor(&n).unwrap();
+    assert!(factor == p || factor == q);
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_siqs_factor_special_cases() {
+    use redc::factor::siqs::siqs_factor;
+    use rug::Integer;
+
+    let q: Integer = "7000000000000000000000000000000000001003".parse().unwrap();
+    assert_eq!(siqs_factor(&q), None);
+    assert_eq!(siqs_factor(&Integer::from(1)), None);
+    assert_eq!(
+        siqs_factor(&Integer::from(&q * 2u32)),
+        Some(Integer::from(2))
+    );
+    assert_eq!(
+        siqs_factor(&Integer::from(&q * 1009u32)),
+        Some(Integer::from(1009))
+    );
+    assert_eq!(siqs_factor(&Integer::from(q.square_ref())), Some(q));
+    assert_eq!(
+        siqs_factor(&Integer::from(2_147_483_647u64 * 4_294_967_291)),
+        Some(Integer::from(2_147_483_647))
+    );
+}
@@ -0,0 +1,123 @@
+mod common;
+
+use common::Goldilocks;
+use redc::constant::ConstPrimeElement;
+use redc::element::FieldOps;
+use redc::Redc;
+
+/// Evaluate the polynomial with the given coefficients (lowest degree first) at x
+fn horner<E: FieldOps>(coefficients: &[u64], x: &E) -> E {
+    coefficients
+        .iter()
+        .rev()
+        .fold(x.zero(), |acc, &c| acc * x.clone() + x.element_from_u64(c))
+}
+
+/// Determinant by gaussian elimination
+fn determinant<E: FieldOps>(mut matrix: Vec<Vec<E>>) -> E {
+    let n = matrix.len();
+    let mut det = matrix[0][0].one();
+    for col in 0..n {
+        let pivot = match (col..n).find(|&row| !matrix[row][col].is_zero()) {
+            Some(pivot) => pivot,
+            None => return det.zero(),
+        };
+        if pivot != col {
+            matrix.swap(pivot, col);
+            det = -det;
+        }
+        let pivot_inv = matrix[col][col].clone().invert();
+        det = det * matrix[col][col].clone();
+        let (upper, lower) = matrix.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col].clone() * pivot_inv.clone();
+            for (entry, pivot_entry) in row.iter_mut().zip(pivot_row).skip(col) {
+                *entry = entry.clone() - factor.clone() * pivot_entry.clone();
+            }
+        }
+    }
+    det
+}
+
+/// n-th fibonacci number by squaring the matrix [[1, 1], [1, 0]]
+fn fibonacci<E: FieldOps>(unit: &E, mut n: u64) -> E {
+    let mul = |a: &[E; 4], b: &[E; 4]| {
+        [
+            a[0].clone() * b[0].clone() + a[1].clone() * b[2].clone(),
+            a[0].clone() * b[1].clone() + a[1].clone() * b[3].clone(),
+            a[2].clone() * b[0].clone() + a[3].clone() * b[2].clone(),
+            a[2].clone() * b[1].clone() + a[3].clone() * b[3].clone(),
+        ]
+    };
+    let mut result = [unit.one(), unit.zero(), unit.zero(), unit.one()];
+    let mut power = [unit.one(), unit.one(), unit.one(), unit.zero()];
+    while n != 0 {
+        if n & 1 == 1 {
+            result = mul(&result, &power);
+        }
+        power = mul(&power, &power);
+        n >>= 1;
+    }
+    result[1].clone()
+}
+
+fn check_generic<E: FieldOps>(x: E) {
+    assert!(x.zero().is_zero());
+    assert!(x.one().is_one());
+    assert!(!x.one().is_zero());
+    assert_eq!(x.clone() + (-x.clone()), x.zero());
+    assert_eq!(x.square(), x.clone() * x.clone());
+    assert_eq!(x.double(), x.element_from_u64(2) * x.clone());
+    assert_eq!(x.pow_u64(5), x.square().square() * x.clone());
+    assert_eq!(x.pow_u64(0), x.one());
+
+    // 1 + 2x + 3x^2 at x = 5
+    assert_eq!(
+        horner(&[1, 2, 3], &x.element_from_u64(5)),
+        x.element_from_u64(86)
+    );
+
+    let m = |v: u64| x.element_from_u64(v);
+    let matrix = vec![
+        vec![m(2), m(0), m(1)],
+        vec![m(1), m(3), m(2)],
+        vec![m(1), m(1), m(2)],
+    ];
+    assert_eq!(determinant(matrix), x.element_from_u64(6));
+    let singular = vec![vec![m(1), m(2)], vec![m(2), m(4)]];
+    assert!(determinant(singular).is_zero());
+
+    assert_eq!(fibonacci(&x, 0), x.zero());
+    assert_eq!(fibonacci(&x, 10), x.element_from_u64(55));
+    assert_eq!(
+        fibonacci(&x, 90),
+        x.element_from_u64(2_880_067_194_370_816_120)
+    );
+}
+
+#[test]
+fn test_field_ops_u64() {
+    let field = 18_446_744_073_709_551_557u64.setup_field();
+    check_generic(field.wrap_element(1_234_567));
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_field_ops_u128() {
+    let field = 340_282_366_920_938_463_463_374_607_431_768_211_297u128.setup_field();
+    check_generic(field.wrap_element(1_234_567));
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_field_ops_rug() {
+    let prime = (rug::Integer::from(1) << 127u32) - 1u32;
+    let field = prime.setup_field();
+    check_generic(field.wrap_element(rug::Integer::from(1_234_567)));
+}
+
+#[test]
+fn test_field_ops_const() {
+    check_generic(ConstPrimeElement::<Goldilocks>::new(1_234_567));
+}
@@ -0,0 +1,117 @@
+mod common;
+
+use common::Goldilocks;
+use redc::constant::{ConstPrimeElement, ConstPrimeModulus};
+use redc::element::Element;
+use redc::fmt::ParseElementError;
+use redc::Redc;
+
+#[test]
+fn test_display_u64() {
+    let field = 1_000_000_007u64.setup_field();
+    let x = field.wrap_element(123_456);
+    assert_eq!(format!("{}", x), "123456");
+    assert_eq!(format!("{:#}", x), "123456 mod 1000000007");
+    assert_eq!(format!("{:x}", x), "1e240");
+    assert_eq!(format!("{:#X}", x), "0x1E240");
+    assert_eq!(format!("{:>8}", x), "  123456");
+    assert_eq!(format!("{:?}", x), "PrimIntElement(123456 mod 1000000007)");
+    assert_eq!(
+        format!("{:?}", Some(x)),
+        "Some(PrimIntElement(123456 mod 1000000007))"
+    );
+}
+
+#[test]
+fn test_parse_u64() {
+    let field = 1_000_000_007u64.setup_field();
+    assert_eq!(field.parse_element("123456").unwrap().to_normal(), 123_456);
+    assert_eq!(field.parse_element("+0x1f").unwrap().to_normal(), 31);
+    assert_eq!(field.parse_element("0X1F").unwrap().to_normal(), 31);
+    assert_eq!(
+        field.parse_element("-1").unwrap().to_normal(),
+        1_000_000_006
+    );
+    assert_eq!(field.parse_element("-0").unwrap().to_normal(), 0);
+    assert_eq!(field.parse_element("1000000008").unwrap().to_normal(), 1);
+    // 10**30 mod p, more digits than fit into a single chunk
+    assert_eq!(
+        field
+            .parse_element("1000000000000000000000000000000")
+            .unwrap()
+            .to_normal(),
+        (0..30).fold(1u64, |acc, _| acc * 10 % 1_000_000_007)
+    );
+    assert_eq!(
+        field
+            .parse_element("0xffffffffffffffffffffffffffffffff")
+            .unwrap()
+            .to_normal(),
+        (u128::MAX % 1_000_000_007) as u64
+    );
+    assert_eq!(field.parse_element(""), Err(ParseElementError::Empty));
+    assert_eq!(field.parse_element("-0x"), Err(ParseElementError::Empty));
+    assert_eq!(
+        field.parse_element("12a"),
+        Err(ParseElementError::InvalidDigit)
+    );
+    assert_eq!(
+        field.parse_element("0x1g"),
+        Err(ParseElementError::InvalidDigit)
+    );
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_fmt_u128() {
+    let prime = 340_282_366_920_938_463_463_374_607_431_768_211_297u128;
+    let field = prime.setup_field();
+    let x = field.parse_element("-2").unwrap();
+    assert_eq!(format!("{}", x), (prime - 2).to_string());
+    assert_eq!(format!("{:x}", x), format!("{:x}", prime - 2));
+    assert_eq!(
+        field
+            .parse_element("0xffffffffffffffffffffffffffffffff")
+            .unwrap()
+            .to_normal(),
+        u128::MAX % prime
+    );
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_fmt_rug() {
+    let prime = (rug::Integer::from(1) << 127u32) - 1u32;
+    let field = prime.clone().setup_field();
+    let x = field.parse_element("-0x10").unwrap();
+    let expected = rug::Integer::from(&prime - 16u32);
+    assert_eq!(format!("{}", x), expected.to_string());
+    assert_eq!(format!("{:#}", x), format!("{} mod {}", expected, prime));
+    assert_eq!(format!("{:X}", x), format!("{:X}", expected));
+    assert_eq!(
+        format!("{:?}", x),
+        format!("RugElement({} mod {})", expected, prime)
+    );
+    assert_eq!(
+        field
+            .parse_element("170141183460469231731687303715884105728")
+            .unwrap()
+            .to_normal(),
+        1
+    );
+}
+
+#[test]
+fn test_fmt_const() {
+    let x: ConstPrimeElement<Goldilocks> = "-1".parse().unwrap();
+    assert_eq!(x.to_u64(), Goldilocks::MODULUS - 1);
+    assert_eq!(format!("{:x}", x), "ffffffff00000000");
+    assert_eq!(
+        format!("{:#}", ConstPrimeElement::<Goldilocks>::new(5)),
+        "5 mod 18446744069414584321"
+    );
+    assert_eq!(
+        "0xz".parse::<ConstPrimeElement<Goldilocks>>(),
+        Err(ParseElementError::InvalidDigit)
+    );
+}
@@ -0,0 +1,157 @@
+use redc::jacobi::{jacobi_u64, kronecker_i64};
+use redc::Redc;
+
+#[test]
+fn test_jacobi_small() {
+    // (a/15) for a = 0..15
+    let expected = [0, 1, 1, 0, 1, 0, 0, -1, 1, 0, 0, -1, 0, -1, -1];
+    for (a, &symbol) in expected.iter().enumerate() {
+        assert_eq!(jacobi_u64(a as u64, 15), symbol);
+    }
+    assert_eq!(jacobi_u64(1001, 9907), -1);
+    assert_eq!(jacobi_u64(19, 45), 1);
+    assert_eq!(jacobi_u64(8, 21), -1);
+    assert_eq!(jacobi_u64(5, 21), 1);
+    assert_eq!(jacobi_u64(0, 1), 1);
+    assert_eq!(jacobi_u64(u64::MAX, u64::MAX), 0);
+}
+
+#[test]
+fn test_jacobi_matches_euler() {
+    for prime in [3u64, 5, 7, 11, 13, 101, 65537] {
+        let field = prime.setup_field();
+        for value in 0..prime.min(500) {
+            let element = field.wrap_element(value);
+            assert_eq!(field.jacobi(&element), element.legendre());
+            assert_eq!(jacobi_u64(value + prime, prime), element.legendre());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_jacobi_even_modulus() {
+    jacobi_u64(3, 10);
+}
+
+#[test]
+fn test_kronecker() {
+    assert_eq!(kronecker_i64(3, 0), 0);
+    assert_eq!(kronecker_i64(-1, 0), 1);
+    assert_eq!(kronecker_i64(4, 6), 0);
+    // (a/2) by a mod 8
+    assert_eq!(kronecker_i64(1, 2), 1);
+    assert_eq!(kronecker_i64(3, 2), -1);
+    assert_eq!(kronecker_i64(-3, 2), -1);
+    assert_eq!(kronecker_i64(7, 2), 1);
+    assert_eq!(kronecker_i64(-1, -1), -1);
+    assert_eq!(kronecker_i64(-1, 7), -1);
+    assert_eq!(
+        kronecker_i64(-5, 12),
+        kronecker_i64(-5, 4) * kronecker_i64(-5, 3)
+    );
+    assert_eq!(kronecker_i64(i64::MIN, i64::MIN), 0);
+    assert_eq!(kronecker_i64(3, i64::MIN), -1);
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_kronecker_matches_rug() {
+    use redc::jacobi::{jacobi_rug, kronecker_rug, kronecker_u64};
+    use rug::Integer;
+
+    for a in -40i64..40 {
+        for n in -40i64..40 {
+            let expected = Integer::from(a).kronecker(&Integer::from(n));
+            assert_eq!(kronecker_i64(a, n), expected, "({}/{})", a, n);
+            if n >= 0 {
+                assert_eq!(kronecker_u64(a, n as u64), expected);
+            }
+            assert_eq!(
+                kronecker_rug(&Integer::from(a), &Integer::from(n)),
+                expected
+            );
+            if n > 0 && n % 2 == 1 {
+                assert_eq!(jacobi_rug(&Integer::from(a), &Integer::from(n)), expected);
+                if a >= 0 {
+                    assert_eq!(jacobi_u64(a as u64, n as u64), expected);
+                }
+            }
+        }
+    }
+    for (a, n) in [
+        (u64::MAX - 2, u64::MAX),
+        (1 << 63, (1 << 61) - 1),
+        (12345, u64::MAX - 58),
+    ] {
+        assert_eq!(jacobi_u64(a, n), Integer::from(a).jacobi(&Integer::from(n)));
+    }
+    for (a, n) in [
+        (3i64, 1u64 << 63),
+        (-5, u64::MAX - 1),
+        (i64::MAX, u64::MAX - 59),
+        (i64::MIN + 1, 6 << 60),
+        (7, 0),
+        (-1, 0),
+    ] {
+        assert_eq!(
+            kronecker_u64(a, n),
+            Integer::from(a).kronecker(&Integer::from(n)),
+            "({}/{})",
+            a,
+            n
+        );
+    }
+
+    let prime = (Integer::from(1) << 127u32) - 1u32;
+    let field = prime.clone().setup_field();
+    for value in 0u32..100 {
+        let element = field.wrap_element(Integer::from(value));
+        assert_eq!(field.jacobi(&element), element.legendre());
+    }
+}
+
+#[cfg(all(feature = "u128", feature = "rug"))]
+#[test]
+fn test_jacobi_u128_matches_rug() {
+    use redc::jacobi::{jacobi_u128, kronecker_i128, kronecker_u128};
+    use rug::Integer;
+
+    for (a, n) in [
+        (u128::MAX - 2, u128::MAX),
+        (1 << 127, (1 << 127) - 1),
+        (12345, u128::MAX - 158),
+        (u128::from(u64::MAX), (1 << 100) + 577),
+    ] {
+        assert_eq!(
+            jacobi_u128(a, n),
+            Integer::from(a).jacobi(&Integer::from(n))
+        );
+    }
+    for (a, n) in [
+        (-5i128, 1 << 100),
+        (i128::MIN, 3),
+        (-7, -(1 << 90) - 1),
+        (i128::MAX, i128::MIN),
+    ] {
+        assert_eq!(
+            kronecker_i128(a, n),
+            Integer::from(a).kronecker(&Integer::from(n))
+        );
+    }
+
+    for (a, n) in [
+        (3i128, 1u128 << 127),
+        (-5, u128::MAX - 1),
+        (i128::MIN + 1, 6 << 124),
+    ] {
+        assert_eq!(
+            kronecker_u128(a, n),
+            Integer::from(a).kronecker(&Integer::from(n))
+        );
+    }
+
+    let field = ((1u128 << 100) + 577).setup_field();
+    let element = field.wrap_element(3);
+    assert_eq!(field.jacobi(&element), element.legendre());
+}
@@ -0,0 +1,67 @@
+use redc::element::Element;
+use redc::Redc;
+
+#[test]
+fn test_lucas_uv_fibonacci() {
+    // U_k(1, -1) are the Fibonacci numbers and V_k(1, -1) the Lucas numbers
+    let prime = 1_000_000_007u64;
+    let field = prime.setup_field();
+    let (mut fibonacci, mut fibonacci_next) = (0u64, 1u64);
+    let (mut lucas, mut lucas_next) = (2u64, 1u64);
+    for k in 0..200u64 {
+        let (u, v) = field.lucas_uv(field.one(), -field.one(), k);
+        assert_eq!(u.to_normal(), fibonacci, "U_{}", k);
+        assert_eq!(v.to_normal(), lucas, "V_{}", k);
+        (fibonacci, fibonacci_next) = (fibonacci_next, (fibonacci + fibonacci_next) % prime);
+        (lucas, lucas_next) = (lucas_next, (lucas + lucas_next) % prime);
+    }
+}
+
+#[test]
+fn test_lucas_uv_recurrence() {
+    // X_{k+1} = P * X_k - Q * X_{k-1} for any P and Q
+    let field = 65537u64.setup_field();
+    let p = field.wrap_element(1234);
+    let q = field.wrap_element(4321);
+    let (mut u, mut u_next) = (field.zero(), field.one());
+    let (mut v, mut v_next) = (field.one() + field.one(), p);
+    for k in 0..100u64 {
+        assert_eq!(field.lucas_uv(p, q, k), (u, v));
+        (u, u_next) = (u_next, p * u_next - q * u);
+        (v, v_next) = (v_next, p * v_next - q * v);
+    }
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_lucas_uv_u128() {
+    // U_p(P, Q) = (D/p) mod p and V_p(P, Q) = P mod p for primes p
+    let prime = u128::MAX - 158;
+    let field = prime.setup_field();
+    let p = field.wrap_element(3);
+    let q = field.wrap_element(1);
+    let (u, v) = field.lucas_uv(p, q, prime);
+    // D = 5, a square modulo p exactly if p = 1, 4 mod 5
+    let symbol = if prime % 5 == 1 || prime % 5 == 4 {
+        field.one()
+    } else {
+        -field.one()
+    };
+    assert_eq!(u, symbol);
+    assert_eq!(v, p);
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_lucas_uv_rug() {
+    use rug::Integer;
+
+    let prime = (Integer::from(1) << 127u32) - 1u32;
+    let field = prime.clone().setup_field();
+    let (u, v) = field.lucas_uv(field.one(), -field.one(), &Integer::from(90));
+    assert_eq!(u.to_normal(), Integer::from(2_880_067_194_370_816_120u64));
+    assert_eq!(v.to_normal(), Integer::from(6_440_026_026_380_244_498u64));
+    let (u, v) = field.lucas_uv(field.wrap_element(Integer::from(3)), field.one(), &prime);
+    assert_eq!(u, -field.one());
+    assert_eq!(v, field.wrap_element(Integer::from(3)));
+}
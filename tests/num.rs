@@ -0,0 +1,84 @@
+use num_traits::{CheckedDiv, Inv, One, Pow, Zero};
+use redc::constant::{ConstPrimeElement, ConstPrimeModulus};
+use redc::element::Element;
+use redc::Redc;
+
+#[test]
+fn test_prim_int_num_traits() {
+    let field = 1_000_000_007u64.setup_field();
+    let x = field.wrap_element(123_456);
+    assert!(field.zero().is_zero());
+    assert!(field.one().is_one());
+    assert_eq!((x * x.inv()).to_normal(), 1);
+    assert_eq!(
+        Pow::pow(x, 3u64).to_normal(),
+        123_456u64.pow(3) % 1_000_000_007
+    );
+    assert_eq!(Pow::pow(x, 0u64).to_normal(), 1);
+    assert_eq!((x / x).to_normal(), 1);
+    assert_eq!(((x * x) / x).to_normal(), 123_456);
+    assert!(x.checked_div(&field.zero()).is_none());
+    assert_eq!(field.zero().checked_div(&x).unwrap().to_normal(), 0);
+    assert_eq!((field.one() + field.zero()).to_normal(), 1);
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_prim_int_num_traits_u128() {
+    let field = ((1u128 << 127) - 1).setup_field();
+    let x = field.wrap_element(u128::from(u64::MAX) * 3);
+    assert_eq!((x * x.inv()).to_normal(), 1);
+    assert_eq!(
+        Pow::pow(x, (1u64 << 63) - 1).to_normal(),
+        Element::pow(x, field.raw_element((1 << 63) - 1)).to_normal()
+    );
+    assert!(x.checked_div(&field.zero()).is_none());
+    assert!(field.one().is_one());
+    assert!(!x.is_one());
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_rug_num_traits() {
+    let field = rug::Integer::from(1_000_000_007).setup_field();
+    let x = field.wrap_element(rug::Integer::from(123_456));
+    assert!(field.zero().is_zero());
+    assert!(field.one().is_one());
+    assert_eq!((x.clone() * x.clone().inv()).to_normal(), 1);
+    assert_eq!(
+        Pow::pow(x.clone(), 3u64).to_normal(),
+        123_456u64.pow(3) % 1_000_000_007
+    );
+    assert!(x.checked_div(&field.zero()).is_none());
+    assert_eq!(x.checked_div(&field.one()).unwrap().to_normal(), 123_456);
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+struct P61;
+
+impl ConstPrimeModulus for P61 {
+    const MODULUS: u64 = (1 << 61) - 1;
+    const GENERATOR: u64 = 37;
+}
+
+type F = ConstPrimeElement<P61>;
+
+#[test]
+fn test_const_num_traits() {
+    fn sum_of_powers<T: Zero + One + Pow<u64, Output = T> + Copy>(x: T, count: u64) -> T {
+        (0..count).fold(T::zero(), |acc, i| acc + x.pow(i))
+    }
+    assert_eq!(sum_of_powers(F::new(2), 10).to_u64(), 1023);
+    assert!(F::zero().is_zero());
+    assert!(F::one().is_one());
+    assert_eq!((F::new(10) / F::new(5)).to_u64(), 2);
+    assert_eq!(F::new(7).inv() * F::new(7), F::one());
+    assert!(F::new(7).checked_div(&F::zero()).is_none());
+}
+
+#[test]
+#[should_panic]
+fn test_division_by_zero() {
+    let field = 23u64.setup_field();
+    let _ = field.one() / field.zero();
+}
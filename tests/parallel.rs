@@ -0,0 +1,69 @@
+#![cfg(feature = "rayon")]
+use redc::element::Element;
+use redc::parallel::{par_batch_invert, par_to_montgomery, par_to_normal};
+use redc::Redc;
+
+#[test]
+fn test_par_conversion() {
+    let modulus = u64::MAX - 58;
+    let field = modulus.setup_field();
+    let values: Vec<u64> = (0..5000)
+        .map(|x: u64| x.wrapping_mul(3_689_348_814_741_910_323))
+        .collect();
+    let montgomery = par_to_montgomery(&values, &field);
+    for (x, m) in values.iter().zip(montgomery.iter()) {
+        assert_eq!(*m, x.to_montgomery(&field));
+    }
+    let normal = par_to_normal(&montgomery, &field);
+    let expected: Vec<u64> = values.iter().map(|x| x % modulus).collect();
+    assert_eq!(normal, expected);
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_par_mod_pow() {
+    use redc::parallel::par_mod_pow;
+    use redc::Field;
+
+    let field = rug::Integer::from(1_000_000_007).setup_field();
+    let bases: Vec<rug::Integer> = (2..200)
+        .map(|x| rug::Integer::from(x).to_montgomery(&field))
+        .collect();
+    let exponent = rug::Integer::from(1_000_000_005);
+    for (base, power) in bases.iter().zip(par_mod_pow(&bases, &exponent, &field)) {
+        let product = field.redc(base.clone() * power).to_normal(&field);
+        assert_eq!(product, 1);
+    }
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_par_arithmetic() {
+    use redc::parallel::{par_add, par_mul, par_sub};
+
+    let modulus = (1u128 << 127) - 1;
+    let field = modulus.setup_field();
+    let lhs: Vec<_> = (1..3000u128).map(|x| field.wrap_element(x * x)).collect();
+    let rhs: Vec<_> = (1..3000u128)
+        .map(|x| field.wrap_element(modulus - x))
+        .collect();
+    let sum = par_add(&lhs, &rhs);
+    let difference = par_sub(&lhs, &rhs);
+    let product = par_mul(&lhs, &rhs);
+    for i in 0..lhs.len() {
+        assert_eq!(sum[i].to_normal(), (lhs[i] + rhs[i]).to_normal());
+        assert_eq!(difference[i].to_normal(), (lhs[i] - rhs[i]).to_normal());
+        assert_eq!(product[i].to_normal(), (lhs[i] * rhs[i]).to_normal());
+    }
+}
+
+#[test]
+fn test_par_batch_invert() {
+    let field = 1_000_000_007u64.setup_field();
+    let original: Vec<_> = (1..5000u64).map(|x| field.wrap_element(x)).collect();
+    let mut inverted = original.clone();
+    par_batch_invert(&mut inverted);
+    for (x, x_inv) in original.into_iter().zip(inverted) {
+        assert_eq!((x * x_inv).to_normal(), 1);
+    }
+}
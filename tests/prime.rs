@@ -0,0 +1,246 @@
+use redc::error::NotPrimeError;
+use redc::prime::{is_prime_u64, next_prime_u64, prev_prime_u64};
+use redc::{PrimIntField, Redc};
+
+fn sieve(limit: usize) -> Vec<bool> {
+    let mut is_prime = vec![true; limit];
+    is_prime[0] = false;
+    is_prime[1] = false;
+    for i in 2..limit {
+        if is_prime[i] {
+            for multiple in (i * i..limit).step_by(i) {
+                is_prime[multiple] = false;
+            }
+        }
+    }
+    is_prime
+}
+
+#[test]
+fn test_is_prime_small() {
+    for (n, &expected) in sieve(100_000).iter().enumerate() {
+        assert_eq!(is_prime_u64(n as u64), expected, "{}", n);
+    }
+}
+
+#[test]
+fn test_is_prime_pseudoprimes() {
+    // Carmichael numbers and strong pseudoprimes to several prime bases
+    for n in [
+        561u64,
+        41041,
+        3_215_031_751,
+        2_152_302_898_747,
+        3_474_749_660_383,
+        341_550_071_728_321,
+        3_825_123_056_546_413_051,
+        18_446_744_066_047_760_377,
+    ] {
+        assert!(!is_prime_u64(n), "{}", n);
+    }
+    for n in [
+        1_000_000_007u64,
+        (1 << 61) - 1,
+        0xffff_ffff_0000_0001,
+        u64::MAX - 58,
+    ] {
+        assert!(is_prime_u64(n), "{}", n);
+    }
+    assert!(!is_prime_u64(u64::MAX));
+    assert!(!is_prime_u64((1 << 32) + 1));
+}
+
+#[test]
+fn test_strong_probable_prime() {
+    // 2047 = 23 * 89 is the smallest strong pseudoprime to base 2
+    let field = 2047u64.setup_field();
+    assert!(field.is_strong_probable_prime(2));
+    assert!(!field.is_strong_probable_prime(3));
+    assert!(!field.is_prime());
+    assert!(field.is_strong_probable_prime(2047 * 5));
+}
+
+#[test]
+fn test_setup_prime_field() {
+    let field = PrimIntField::<u64>::setup_prime_field(1_000_000_007).unwrap();
+    assert_eq!(field.modulus(), 1_000_000_007);
+    assert_eq!(
+        PrimIntField::<u64>::setup_prime_field(2047).unwrap_err(),
+        NotPrimeError
+    );
+    assert!(PrimIntField::<u64>::setup_prime_field(2).is_err());
+    assert!(PrimIntField::<u64>::setup_prime_field(1).is_err());
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_is_prime_u128() {
+    use redc::prime::is_prime_u128;
+
+    for n in [
+        (1u128 << 127) - 1,
+        u128::MAX - 158,
+        (1 << 64) + 13,
+        (1 << 89) - 1,
+        (1 << 100) + 577,
+        170_141_183_460_469_231_731_687_303_715_884_105_757,
+    ] {
+        assert!(is_prime_u128(n), "{}", n);
+    }
+    for n in [
+        u128::MAX,
+        (1u128 << 64) + 1,
+        (1 << 127) + 1,
+        u128::from(u64::MAX - 58) * u128::from(u64::MAX - 82),
+        // Strong pseudoprime to the first twelve prime bases
+        318_665_857_834_031_151_167_461,
+    ] {
+        assert!(!is_prime_u128(n), "{}", n);
+    }
+    assert_eq!(is_prime_u128(97), is_prime_u64(97));
+    assert!(PrimIntField::<u128>::setup_prime_field(u128::MAX).is_err());
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_is_prime_matches_rug() {
+    use rug::integer::IsPrime;
+    use rug::Integer;
+
+    let mut n = 0x9e37_79b9_7f4a_7c15u64;
+    for _ in 0..20_000 {
+        n = n
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        let candidate = n | 1;
+        let expected = Integer::from(candidate).is_probably_prime(30) != IsPrime::No;
+        assert_eq!(is_prime_u64(candidate), expected, "{}", candidate);
+    }
+}
+
+#[test]
+fn test_strong_lucas_pseudoprimes() {
+    // The smallest strong Lucas pseudoprimes with Selfridge's parameters, all caught by base 2
+    for n in [
+        5459u64, 5777, 10877, 16109, 18971, 22499, 24569, 25199, 40309, 58519,
+    ] {
+        let field = n.setup_field();
+        assert!(field.is_strong_lucas_probable_prime(), "{}", n);
+        assert!(!field.is_strong_probable_prime(2), "{}", n);
+        assert!(!is_prime_u64(n));
+    }
+    for n in [3u64, 5, 7, 101, 1_000_000_007, u64::MAX - 58] {
+        assert!(n.setup_field().is_strong_lucas_probable_prime(), "{}", n);
+    }
+    // Squares and numbers with a small D factor
+    assert!(!9u64.setup_field().is_strong_lucas_probable_prime());
+    assert!(!(65537u64 * 65537)
+        .setup_field()
+        .is_strong_lucas_probable_prime());
+    assert!(!(7u64 * 1_000_000_007)
+        .setup_field()
+        .is_strong_lucas_probable_prime());
+}
+
+#[cfg(all(feature = "u128", feature = "rug"))]
+#[test]
+fn test_is_prime_u128_matches_rug() {
+    use redc::prime::is_prime_u128;
+    use rug::integer::IsPrime;
+    use rug::Integer;
+
+    let mut n = 0x9e37_79b9_7f4a_7c15_f39c_c060_5ced_c834u128;
+    for _ in 0..5_000 {
+        n = n
+            .wrapping_mul(0x2360_ed05_1fc6_5da4_4385_df64_9fcc_f645)
+            .wrapping_add(0x5851_f42d_4c95_7f2d_1405_7b7e_f767_814f);
+        let candidate = (n >> (n % 100)) | 1;
+        let expected = Integer::from(candidate).is_probably_prime(30) != IsPrime::No;
+        assert_eq!(is_prime_u128(candidate), expected, "{}", candidate);
+    }
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_is_prime_rug() {
+    use redc::prime::is_prime_rug;
+    use redc::RugField;
+    use rug::integer::IsPrime;
+    use rug::Integer;
+
+    for exponent in [61u32, 89, 107, 127, 521, 607, 1279] {
+        let mersenne = (Integer::from(1) << exponent) - 1u32;
+        assert!(is_prime_rug(&mersenne), "2**{} - 1", exponent);
+    }
+    for exponent in [67u32, 101, 257] {
+        assert!(!is_prime_rug(&((Integer::from(1) << exponent) - 1u32)));
+    }
+    for n in 0u32..3000 {
+        let n = Integer::from(n);
+        assert_eq!(
+            is_prime_rug(&n),
+            n.is_probably_prime(30) != IsPrime::No,
+            "{}",
+            n
+        );
+    }
+    let field = RugField::setup_prime_field((Integer::from(1) << 255u32) - 19u32).unwrap();
+    assert!(field.is_strong_probable_prime(&Integer::from(2)));
+    assert!(RugField::setup_prime_field(Integer::from(5459)).is_err());
+}
+
+#[test]
+fn test_next_and_prev_prime() {
+    let is_prime = sieve(10_000);
+    for n in 0..9_000u64 {
+        let next = (n + 1..).find(|&m| is_prime[m as usize]);
+        let prev = (0..n).rev().find(|&m| is_prime[m as usize]);
+        assert_eq!(next_prime_u64(n), next, "{}", n);
+        assert_eq!(prev_prime_u64(n), prev, "{}", n);
+    }
+    assert_eq!(next_prime_u64(1_000_000_000), Some(1_000_000_007));
+    assert_eq!(prev_prime_u64(1_000_000_007), Some(999_999_937));
+    assert_eq!(prev_prime_u64(u64::MAX), Some(u64::MAX - 58));
+    assert_eq!(next_prime_u64(u64::MAX - 58), None);
+    assert_eq!(next_prime_u64(u64::MAX - 59), Some(u64::MAX - 58));
+}
+
+#[test]
+fn test_ntt_prime() {
+    // Goldilocks, 2**64 - 2**32 + 1
+    let field = PrimIntField::<u64>::ntt_prime(32, 64).unwrap();
+    assert_eq!(field.modulus(), 0xffff_ffff_0000_0001);
+    // 15 * 2**27 + 1, the BabyBear prime
+    assert_eq!(
+        PrimIntField::<u64>::ntt_prime(27, 31).unwrap().modulus(),
+        2_013_265_921
+    );
+    // 119 * 2**23 + 1
+    assert_eq!(
+        PrimIntField::<u64>::ntt_prime(23, 30).unwrap().modulus(),
+        998_244_353
+    );
+    for (two_adicity, bits) in [(1, 5), (10, 20), (40, 62), (20, 64)] {
+        let prime = PrimIntField::<u64>::ntt_prime(two_adicity, bits)
+            .unwrap()
+            .modulus();
+        assert!(is_prime_u64(prime));
+        assert_eq!((prime - 1) % (1 << two_adicity), 0);
+        assert!(prime < 1 << (bits - 1) << 1 || bits == 64);
+    }
+    assert!(PrimIntField::<u64>::ntt_prime(3, 4).is_none());
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_prime_search_u128() {
+    use redc::prime::{next_prime_u128, prev_prime_u128};
+
+    assert_eq!(prev_prime_u128(u128::MAX), Some(u128::MAX - 158));
+    assert_eq!(next_prime_u128(u128::MAX - 158), None);
+    assert_eq!(next_prime_u128(1 << 64), Some((1 << 64) + 13));
+    assert_eq!(next_prime_u128(100), Some(101));
+    let field = PrimIntField::<u128>::ntt_prime(64, 128).unwrap();
+    assert_eq!((field.modulus() - 1) % (1 << 64), 0);
+    assert!(field.modulus() > 1 << 127);
+}
@@ -0,0 +1,145 @@
+#![cfg(feature = "rand")]
+use rand::rngs::SmallRng;
+use rand::SeedableRng;
+use redc::element::Element;
+use redc::Redc;
+
+#[test]
+fn test_random_u64_uniform() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let field = 11u64.setup_field();
+    let mut counts = [0u32; 11];
+    for _ in 0..11_000 {
+        counts[field.random(&mut rng).to_normal() as usize] += 1;
+    }
+    // Every residue is hit roughly a thousand times
+    assert!(
+        counts.iter().all(|&c| (850..1150).contains(&c)),
+        "{:?}",
+        counts
+    );
+
+    assert!((0..1000).all(|_| field.random_nonzero(&mut rng).to_normal() != 0));
+}
+
+#[test]
+fn test_random_u64_range() {
+    let mut rng = SmallRng::seed_from_u64(2);
+    for prime in [
+        3u64,
+        1_000_000_007,
+        (1 << 63) + 29,
+        18_446_744_073_709_551_557,
+    ] {
+        let field = prime.setup_field();
+        for _ in 0..1000 {
+            let x = field.random(&mut rng);
+            assert!(*x.internal() < prime);
+            let y = field.random_invertible(&mut rng);
+            assert_eq!((y * y.invert()).to_normal(), 1);
+        }
+    }
+}
+
+#[test]
+fn test_random_invertible_composite() {
+    let mut rng = SmallRng::seed_from_u64(3);
+    let field = (3u64 * 5 * 7).setup_field();
+    for _ in 0..1000 {
+        let x = field.random_invertible(&mut rng).to_normal();
+        assert!(
+            !x.is_multiple_of(3) && !x.is_multiple_of(5) && !x.is_multiple_of(7),
+            "{}",
+            x
+        );
+    }
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_random_u128() {
+    let mut rng = SmallRng::seed_from_u64(4);
+    let prime = 340_282_366_920_938_463_463_374_607_431_768_211_297u128;
+    let field = prime.setup_field();
+    let mut high_bit = false;
+    for _ in 0..1000 {
+        let x = field.random_nonzero(&mut rng);
+        assert!(*x.internal() < prime && *x.internal() != 0);
+        high_bit |= x.to_normal() >> 127 == 1;
+    }
+    assert!(high_bit);
+    let field = 21u128.setup_field();
+    for _ in 0..100 {
+        let x = field.random_invertible(&mut rng).to_normal();
+        assert!(!x.is_multiple_of(3) && !x.is_multiple_of(7));
+    }
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_random_rug() {
+    let mut rng = SmallRng::seed_from_u64(5);
+    let prime = (rug::Integer::from(1) << 127u32) - 1u32;
+    let field = prime.clone().setup_field();
+    for _ in 0..1000 {
+        let x = field.random_invertible(&mut rng);
+        assert!(*x.internal() < prime && *x.internal() != 0);
+    }
+    let field = rug::Integer::from(7u32).setup_field();
+    let mut counts = [0u32; 7];
+    for _ in 0..7000 {
+        counts[field.random(&mut rng).to_normal().to_usize().unwrap()] += 1;
+    }
+    assert!(
+        counts.iter().all(|&c| (850..1150).contains(&c)),
+        "{:?}",
+        counts
+    );
+    let field = rug::Integer::from(15u32).setup_field();
+    for _ in 0..100 {
+        let x = field.random_invertible(&mut rng).to_normal();
+        assert!(!x.is_divisible_u(3) && !x.is_divisible_u(5));
+    }
+}
+
+#[test]
+fn test_random_prime() {
+    use redc::prime::is_prime_u64;
+    use redc::PrimIntField;
+
+    let mut rng = SmallRng::seed_from_u64(5);
+    for bits in [2u32, 3, 8, 17, 32, 63, 64] {
+        for _ in 0..10 {
+            let prime = PrimIntField::<u64>::random_prime(bits, &mut rng).modulus();
+            assert!(is_prime_u64(prime));
+            assert_eq!(64 - prime.leading_zeros(), bits);
+        }
+    }
+    for bits in [3u32, 5, 16, 40, 64] {
+        let prime = PrimIntField::<u64>::random_safe_prime(bits, &mut rng).modulus();
+        assert!(is_prime_u64(prime) && is_prime_u64(prime / 2), "{}", prime);
+        assert_eq!(64 - prime.leading_zeros(), bits);
+    }
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_random_prime_u128() {
+    use redc::prime::is_prime_u128;
+    use redc::PrimIntField;
+
+    let mut rng = SmallRng::seed_from_u64(6);
+    for bits in [2u32, 65, 100, 128] {
+        let prime = PrimIntField::<u128>::random_prime(bits, &mut rng).modulus();
+        assert!(is_prime_u128(prime));
+        assert_eq!(128 - prime.leading_zeros(), bits);
+    }
+    let prime = PrimIntField::<u128>::random_safe_prime(96, &mut rng).modulus();
+    assert!(is_prime_u128(prime) && is_prime_u128(prime / 2));
+}
+
+#[test]
+#[should_panic]
+fn test_random_prime_too_wide() {
+    redc::PrimIntField::<u64>::random_prime(65, &mut SmallRng::seed_from_u64(7));
+}
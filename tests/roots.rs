@@ -0,0 +1,157 @@
+use redc::element::Element;
+use redc::Redc;
+
+fn brute_force_roots(value: u64, k: u32, prime: u64) -> Vec<u64> {
+    (0..prime)
+        .filter(|&x| {
+            let mut power = 1;
+            for _ in 0..k {
+                power = power * x % prime;
+            }
+            power == value
+        })
+        .collect()
+}
+
+#[test]
+fn test_nth_root_small_fields() {
+    for prime in [3u64, 5, 7, 13, 19, 31, 37, 73, 109, 163] {
+        let field = prime.setup_field();
+        for k in 1..=12 {
+            for value in 0..prime {
+                let expected = brute_force_roots(value, k, prime);
+                let x = field.wrap_element(value);
+                match x.nth_root(k) {
+                    Some(root) => assert!(expected.contains(&root.to_normal())),
+                    None => assert!(
+                        expected.is_empty(),
+                        "{}th root of {} mod {}",
+                        k,
+                        value,
+                        prime
+                    ),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_all_nth_roots_small_fields() {
+    for prime in [3u64, 5, 7, 13, 19, 31, 37, 73, 109, 163] {
+        let field = prime.setup_field();
+        for k in 1..=12 {
+            for value in 0..prime {
+                let mut roots: Vec<u64> = field
+                    .wrap_element(value)
+                    .all_nth_roots(k)
+                    .into_iter()
+                    .map(|r| r.to_normal())
+                    .collect();
+                roots.sort_unstable();
+                let expected = brute_force_roots(value, k, prime);
+                assert_eq!(roots, expected, "{}th roots of {} mod {}", k, value, prime);
+            }
+        }
+    }
+}
+
+/// 1 mod 3 with 3**2 dividing prime - 1, and 2 mod 3 where cube roots are unique
+const CUBE_ROOT_PRIMES: [u64; 4] = [
+    0xffff_ffff_0000_0001,
+    1_000_000_007,
+    (1 << 61) - 1,
+    u64::MAX - 58,
+];
+
+#[test]
+fn test_cube_root_u64() {
+    for prime in CUBE_ROOT_PRIMES {
+        let field = prime.setup_field();
+        for value in [2u64, 12345, prime - 1] {
+            let x = field.wrap_element(value);
+            let cube = x * x * x;
+            let root = cube.nth_root(3).unwrap();
+            assert_eq!(root * root * root, cube);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_all_cube_roots_u64() {
+    for prime in CUBE_ROOT_PRIMES {
+        let field = prime.setup_field();
+        for value in [2u64, 12345, prime - 1] {
+            let x = field.wrap_element(value);
+            let roots = (x * x * x).all_nth_roots(3);
+            let expected = if prime % 3 == 1 { 3 } else { 1 };
+            assert_eq!(roots.len(), expected);
+            assert!(roots.contains(&x));
+        }
+    }
+}
+
+#[test]
+fn test_nth_root_goldilocks() {
+    // prime - 1 = 2**32 * 3 * 5 * 17 * 257 * 65537
+    let prime = 0xffff_ffff_0000_0001u64;
+    let field = prime.setup_field();
+    let x = field.wrap_element(0x1234_5678_9abc);
+    for k in [2u32, 4, 5, 16, 64, 1 << 20, 17 * 257, 7, 11 * 3] {
+        let power = num_traits::Pow::pow(x, u64::from(k));
+        let root = power.nth_root(k).unwrap();
+        assert_eq!(num_traits::Pow::pow(root, u64::from(k)), power);
+    }
+    // 7 is a generator, so no proper power
+    assert!(field.wrap_element(7).nth_root(3).is_none());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_all_nth_roots_goldilocks() {
+    let field = 0xffff_ffff_0000_0001u64.setup_field();
+    assert!(field.wrap_element(7).all_nth_roots(2).is_empty());
+    assert_eq!(field.wrap_element(1).all_nth_roots(1 << 10).len(), 1 << 10);
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_nth_root_u128() {
+    for prime in [(1u128 << 127) - 1, u128::MAX - 158, (1 << 100) + 577] {
+        let field = prime.setup_field();
+        let x = field.wrap_element(0x1234_5678_9abc_def0_1234);
+        for k in [3u32, 5, 7, 9, 64] {
+            let power = num_traits::Pow::pow(x, u64::from(k));
+            let root = power.nth_root(k).unwrap();
+            assert_eq!(num_traits::Pow::pow(root, u64::from(k)), power);
+            assert!(power.all_nth_roots(k).contains(&x));
+        }
+    }
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_nth_root_rug() {
+    use rug::Integer;
+
+    for prime in [
+        (Integer::from(1) << 255u32) - 19u32,
+        (Integer::from(1) << 224u32) - (Integer::from(1) << 96u32) + 1u32,
+    ] {
+        let field = prime.setup_field();
+        let x = field.wrap_element(Integer::from(0x1234_5678_9abc_u64));
+        for k in [2u32, 3, 4, 5, 12] {
+            let power = num_traits::Pow::pow(x.clone(), u64::from(k));
+            let root = power.nth_root(k).unwrap();
+            assert_eq!(num_traits::Pow::pow(root, u64::from(k)), power);
+            let roots = power.all_nth_roots(k);
+            assert!(roots.contains(&x));
+            assert!(roots
+                .iter()
+                .all(|r| num_traits::Pow::pow(r.clone(), u64::from(k)) == power));
+        }
+        assert_eq!(field.zero().all_nth_roots(3), vec![field.zero()]);
+    }
+}
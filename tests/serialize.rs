@@ -0,0 +1,87 @@
+#![cfg(feature = "serde")]
+mod common;
+
+use common::Goldilocks;
+use redc::constant::{ConstPrimeElement, ConstPrimeModulus};
+use redc::element::Element;
+use redc::serialize::ElementSeed;
+use redc::{PrimIntField, Redc};
+use serde::de::DeserializeSeed;
+
+fn deserialize_element<'a, F, V>(field: &'a F, json: &str) -> Result<V, serde_json::Error>
+where
+    ElementSeed<'a, F>: for<'de> DeserializeSeed<'de, Value = V>,
+{
+    ElementSeed(field).deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+#[test]
+fn test_serde_u64() {
+    let field = 1_000_000_007u64.setup_field();
+    let json = serde_json::to_string(&field).unwrap();
+    assert_eq!(json, "1000000007");
+    let loaded: PrimIntField<u64> = serde_json::from_str(&json).unwrap();
+
+    let x = field.wrap_element(123_456) * field.wrap_element(789);
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, (123_456u64 * 789).to_string());
+    let y = deserialize_element(&loaded, &json).unwrap();
+    assert_eq!(y.to_normal(), x.to_normal());
+    assert_eq!(
+        (y * loaded.wrap_element(2)).to_normal(),
+        (x + x).to_normal()
+    );
+
+    assert!(deserialize_element(&field, "1000000007").is_err());
+    assert!(serde_json::from_str::<PrimIntField<u64>>("1000000008").is_err());
+    let one = serde_json::from_str::<PrimIntField<u64>>("1").unwrap_err();
+    assert!(one.to_string().contains("odd modulus greater than 1"));
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_serde_u128() {
+    let prime = 340_282_366_920_938_463_463_374_607_431_768_211_297u128;
+    let field = prime.setup_field();
+    let loaded: PrimIntField<u128> =
+        serde_json::from_str(&serde_json::to_string(&field).unwrap()).unwrap();
+    let x = field.wrap_element(prime - 5);
+    let y = deserialize_element(&loaded, &serde_json::to_string(&x).unwrap()).unwrap();
+    assert_eq!(y.to_normal(), prime - 5);
+    assert!(deserialize_element(&field, &prime.to_string()).is_err());
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_serde_rug() {
+    let prime = (rug::Integer::from(1) << 127u32) - 1u32;
+    let field = prime.clone().setup_field();
+    let loaded: redc::RugField =
+        serde_json::from_str(&serde_json::to_string(&field).unwrap()).unwrap();
+    let x = field.wrap_element(rug::Integer::from(&prime - 5u32));
+    let y = deserialize_element(&loaded, &serde_json::to_string(&x).unwrap()).unwrap();
+    assert_eq!(y.to_normal(), rug::Integer::from(&prime - 5u32));
+
+    let too_large = serde_json::to_string(&prime).unwrap();
+    assert!(deserialize_element(&field, &too_large).is_err());
+    let negative = serde_json::to_string(&rug::Integer::from(-1)).unwrap();
+    assert!(deserialize_element(&field, &negative).is_err());
+    let even = serde_json::to_string(&(rug::Integer::from(1u32) << 64u32)).unwrap();
+    assert!(serde_json::from_str::<redc::RugField>(&even).is_err());
+    assert!(serde_json::from_str::<redc::RugField>("1").is_err());
+}
+
+#[test]
+fn test_serde_const() {
+    let x = ConstPrimeElement::<Goldilocks>::new(42);
+    let json = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, "42");
+    assert_eq!(
+        serde_json::from_str::<ConstPrimeElement<Goldilocks>>(&json).unwrap(),
+        x
+    );
+    assert!(serde_json::from_str::<ConstPrimeElement<Goldilocks>>(
+        &Goldilocks::MODULUS.to_string()
+    )
+    .is_err());
+}
@@ -0,0 +1,88 @@
+use redc::element::Element;
+use redc::Redc;
+
+#[test]
+fn test_i32_kyber() {
+    let modulus = 3329i32;
+    let field = modulus.setup_field();
+    let mut x = 17i64;
+    let mut n = field.wrap_element(17);
+    let increment = field.wrap_element(-1234);
+    for _ in 0..1000 {
+        x = (x * x - 1234).rem_euclid(i64::from(modulus));
+        n = n * n + increment;
+        let normal = n.to_normal();
+        assert!(-modulus < normal && normal < modulus);
+        assert_eq!(i64::from(normal).rem_euclid(i64::from(modulus)), x);
+    }
+}
+
+#[test]
+fn test_i64_mersenne() {
+    let modulus = (1i64 << 61) - 1;
+    let field = modulus.setup_field();
+    let mut x = 3i128;
+    let mut n = field.wrap_element(3);
+    let decrement = field.wrap_element(modulus - 5);
+    for _ in 0..1000 {
+        x = (x * x - i128::from(modulus - 5)).rem_euclid(i128::from(modulus));
+        n = n * n - decrement;
+        assert_eq!(i128::from(n.to_normal()).rem_euclid(i128::from(modulus)), x);
+    }
+}
+
+#[test]
+fn test_centered() {
+    let field = 8_380_417i32.setup_field();
+    assert_eq!(field.wrap_element(8_380_416).to_centered(), -1);
+    assert_eq!(field.wrap_element(-8_380_416).to_centered(), 1);
+    assert_eq!(field.wrap_element(4_190_208).to_centered(), 4_190_208);
+    assert_eq!(field.wrap_element(4_190_209).to_centered(), -4_190_208);
+    for value in [-8_380_416, -4_190_209, -1, 0, 1, 4_190_209, 8_380_416] {
+        let centered = field.to_centered(value);
+        assert!(centered.abs() <= 4_190_208);
+        assert_eq!((value - centered) % 8_380_417, 0);
+    }
+}
+
+#[test]
+fn test_invert() {
+    let field = 12289i32.setup_field();
+    for value in 1..500 {
+        let x = field.wrap_element(value);
+        assert_eq!((x * x.invert()).to_centered(), 1);
+    }
+}
+
+#[test]
+fn test_unsigned_interop() {
+    let field = 3329i32.setup_field();
+    let unsigned = field.unsigned_field();
+    for value in -3328..3329 {
+        let signed = field.wrap_element(value);
+        let converted = signed.to_unsigned(&unsigned);
+        assert_eq!(converted.to_normal(), value.rem_euclid(3329) as u64);
+        assert_eq!(
+            field.from_unsigned(converted).to_centered(),
+            signed.to_centered()
+        );
+    }
+
+    let field = ((1i64 << 61) - 1).setup_field();
+    let unsigned = field.unsigned_field();
+    let a = field.wrap_element(-42);
+    let b = unsigned.wrap_element(1 << 60);
+    let product = a.to_unsigned(&unsigned) * b;
+    assert_eq!(
+        (a * field.from_unsigned(b))
+            .to_unsigned(&unsigned)
+            .to_normal(),
+        product.to_normal()
+    );
+}
+
+#[test]
+#[should_panic(expected = "Prime 1073741824 needs to lie in (0, 2**30)")]
+fn test_modulus_too_large() {
+    (1i32 << 30).setup_field();
+}
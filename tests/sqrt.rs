@@ -0,0 +1,157 @@
+mod common;
+
+use redc::element::Element;
+use redc::Redc;
+
+/// Primes covering all three branches: 3 mod 4, 5 mod 8 and 1 mod 8 with a large two-adicity
+const PRIMES: [u64; 8] = [
+    7,
+    13,
+    17,
+    1_000_000_007,
+    998_244_353,
+    0xffff_ffff_0000_0001,
+    (1 << 61) - 1,
+    18_446_744_073_709_551_557,
+];
+
+#[test]
+fn test_sqrt_small_fields() {
+    for prime in [3u64, 5, 7, 11, 13, 17, 41, 73, 97, 113, 193, 257] {
+        let field = prime.setup_field();
+        let squares: Vec<u64> = (1..prime).map(|x| x * x % prime).collect();
+        for value in 0..prime {
+            let x = field.wrap_element(value);
+            let is_square = value == 0 || squares.contains(&value);
+            match x.sqrt() {
+                Some(root) => {
+                    assert!(is_square, "{} mod {}", value, prime);
+                    assert_eq!((root * root).to_normal(), value);
+                }
+                None => assert!(!is_square, "{} mod {}", value, prime),
+            }
+            let expected = if value == 0 {
+                0
+            } else if is_square {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(x.legendre(), expected, "{} mod {}", value, prime);
+        }
+    }
+}
+
+#[test]
+fn test_sqrt_u64() {
+    for prime in PRIMES {
+        let field = prime.setup_field();
+        for value in [2u64, 3, 12345, prime - 1, prime / 3] {
+            let x = field.wrap_element(value);
+            let square = x * x;
+            assert_eq!(square.legendre(), 1);
+            let root = square.sqrt().unwrap();
+            assert!(root == x || root == -x, "{} mod {}", value, prime);
+        }
+    }
+}
+
+#[test]
+fn test_sqrt_non_residue() {
+    for prime in PRIMES {
+        let field = prime.setup_field();
+        let non_residue = (2..)
+            .map(|v| field.wrap_element(v))
+            .find(|x| x.legendre() == -1)
+            .unwrap();
+        assert!(non_residue.sqrt().is_none());
+        assert!((non_residue * field.wrap_element(4)).sqrt().is_none());
+    }
+}
+
+#[test]
+fn test_non_residue_precomputed() {
+    for prime in PRIMES {
+        let field = prime.setup_field();
+        let least = (2..)
+            .find(|&v| field.wrap_element(v).legendre() == -1)
+            .unwrap();
+        let expected = (prime % 8 == 1).then_some(least);
+        assert_eq!(field.non_residue(), expected, "{}", prime);
+        assert_eq!(
+            redc::PrimIntField::setup_const(prime).non_residue(),
+            expected
+        );
+    }
+    // Composite rings get no non-residue if they are squares
+    assert_eq!((17u64 * 17).setup_field().non_residue(), None);
+}
+
+#[test]
+fn test_sqrt_const_field() {
+    use common::Goldilocks;
+    use redc::constant::ConstPrimeElement;
+
+    let x = ConstPrimeElement::<Goldilocks>::new(1234).as_element();
+    assert_eq!((x * x).sqrt().map(|r| r * r), Some(x * x));
+}
+
+#[cfg(feature = "u128")]
+#[test]
+fn test_sqrt_u128() {
+    // 3 mod 4, 3 mod 4, 5 mod 8 and 1 mod 64
+    for prime in [
+        (1u128 << 127) - 1,
+        u128::MAX - 158,
+        (1u128 << 64) + 13,
+        (1 << 100) + 577,
+    ] {
+        let field = prime.setup_field();
+        for value in [2u128, 3, 1 << 100, prime - 1] {
+            let x = field.wrap_element(value);
+            let root = (x * x).sqrt().unwrap();
+            assert!(root == x || root == -x);
+        }
+    }
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_sqrt_rug() {
+    use rug::Integer;
+
+    let primes = [
+        (Integer::from(1) << 127u32) - 1u32,
+        Integer::from(1_000_000_009),
+        Integer::from(998_244_353),
+        // 2**255 - 19, = 5 mod 8
+        (Integer::from(1) << 255u32) - 19u32,
+        // 2**224 - 2**96 + 1, two-adicity 96
+        (Integer::from(1) << 224u32) - (Integer::from(1) << 96u32) + 1u32,
+    ];
+    for prime in primes {
+        let field = prime.clone().setup_field();
+        for value in [
+            Integer::from(2),
+            Integer::from(&prime - 5u32),
+            Integer::from(&prime >> 3u32),
+        ] {
+            let x = field.wrap_element(value);
+            let square = x.clone() * x.clone();
+            assert_eq!(square.legendre(), 1);
+            let root = square.sqrt().unwrap();
+            assert!(root == x || root == -x);
+        }
+        let non_residue = (2u32..)
+            .map(|v| field.wrap_element(Integer::from(v)))
+            .find(|x| x.legendre() == -1)
+            .unwrap();
+        assert!(non_residue.sqrt().is_none());
+        if prime.mod_u(8) == 1 {
+            assert_eq!(field.non_residue(), Some(&non_residue.to_normal()));
+        } else {
+            assert_eq!(field.non_residue(), None);
+        }
+        assert_eq!(field.zero().sqrt(), Some(field.zero()));
+    }
+}
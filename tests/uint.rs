@@ -0,0 +1,104 @@
+#![cfg(feature = "crypto-bigint")]
+use crypto_bigint::{U256, U64};
+use redc::element::Element;
+use redc::{Field, Redc};
+
+#[test]
+fn test_redc_uint() {
+    let field = U64::from_u64(23).setup_field();
+    let a = U64::from_u64(7).to_montgomery(&field);
+    let b = U64::from_u64(17).to_montgomery(&field);
+    // Same R as the u64 field
+    assert_eq!(a, U64::from_u64(7u64.to_montgomery(&23u64.setup_field())));
+    let r = field.redc(a.mul_wide(&b));
+    assert_eq!(r.to_normal(&field), U64::from_u64(4));
+}
+
+#[test]
+fn test_matches_u64() {
+    let modulus = u64::MAX - 58;
+    let field = modulus.setup_field();
+    let uint_field = U64::from_u64(modulus).setup_field();
+    let mut n = field.wrap_element(0xdead_beef);
+    let mut u = uint_field.wrap_element(U64::from_u64(0xdead_beef));
+    let increment = field.wrap_element(u64::MAX - 60);
+    let uint_increment = uint_field.wrap_element(U64::from_u64(u64::MAX - 60));
+    for _ in 0..1000 {
+        n = n * n + increment;
+        u = u * u + uint_increment;
+        assert_eq!(U64::from_u64(n.to_normal()), u.to_normal());
+        n = n - increment - increment;
+        u = u - uint_increment - uint_increment;
+        assert_eq!(U64::from_u64(n.to_normal()), u.to_normal());
+    }
+}
+
+#[test]
+fn test_invert_p256() {
+    let prime =
+        U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+    let field = prime.setup_field();
+    for value in [2u64, 3, 0xdead_beef, u64::MAX] {
+        let x = field.wrap_element(U256::from_u64(value));
+        assert_eq!((x * x.invert()).to_normal(), U256::ONE);
+    }
+    let minus_one = field.wrap_element(prime.wrapping_sub(&U256::ONE));
+    assert_eq!((minus_one * minus_one).to_normal(), U256::ONE);
+    assert_eq!(
+        (minus_one + minus_one).to_normal(),
+        prime.wrapping_sub(&U256::from_u8(2))
+    );
+}
+
+#[cfg(feature = "rug")]
+#[test]
+fn test_matches_rug() {
+    fn to_rug(value: &U256) -> rug::Integer {
+        rug::Integer::from_str_radix(&format!("{:x}", value), 16).unwrap()
+    }
+    fn to_uint(value: &rug::Integer) -> U256 {
+        U256::from_be_hex(&format!("{:064x}", value))
+    }
+    // 2**255 - 19
+    let prime: rug::Integer = (rug::Integer::from(1) << 255) - 19;
+    let rug_field = prime.clone().setup_field();
+    let uint_field = to_uint(&prime).setup_field();
+    let base: rug::Integer = rug::Integer::from(0xfeed_beef_u64) << 100;
+    // The rug field uses R = 2**255, the uint field R = 2**256
+    let doubled: rug::Integer = base.clone().to_montgomery(&rug_field) << 1;
+    assert_eq!(
+        to_rug(&to_uint(&base).to_montgomery(&uint_field)),
+        doubled % &prime
+    );
+    for exponent in [0u64, 1, 2, 65537, u64::MAX] {
+        let expected = base
+            .clone()
+            .pow_mod(&rug::Integer::from(exponent), &prime)
+            .unwrap();
+        let actual = to_uint(&base)
+            .to_montgomery(&uint_field)
+            .mod_pow(U256::from_u64(exponent), &uint_field)
+            .to_normal(&uint_field);
+        assert_eq!(to_rug(&actual), expected);
+    }
+}
+
+#[test]
+fn test_uint_bytes() {
+    let prime =
+        U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+    let field = prime.setup_field();
+    let value = U256::from_u64(0x0102_0304);
+    let x = field.wrap_element(value);
+    let mut expected = [0u8; 32];
+    expected[..4].copy_from_slice(&[4, 3, 2, 1]);
+    assert_eq!(x.to_bytes_le(), expected);
+    expected.reverse();
+    assert_eq!(x.to_bytes_be(), expected);
+    assert_eq!(field.from_bytes_be(expected).unwrap().to_normal(), value);
+    assert!(field.from_bytes_le([0xff; 32]).is_none());
+
+    let reduced = field.from_uniform_bytes(&[0xff; 64]).to_normal();
+    let expected = U256::const_rem_wide((U256::MAX, U256::MAX), &prime).0;
+    assert_eq!(reduced, expected);
+}